@@ -0,0 +1,110 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use ndarray::{Array, Array2};
+use ndarray_rand::rand::rngs::StdRng;
+use ndarray_rand::rand::SeedableRng;
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+use scouter::core::monitor::Monitor;
+use scouter::utils::types::{DriftConfig, DriftServerRecord};
+
+// seeded so that runs are comparable across releases
+fn dataset(rows: usize, features: usize) -> (Array2<f64>, Vec<String>) {
+    let mut rng = StdRng::seed_from_u64(42);
+    let array = Array::random_using((rows, features), Uniform::new(0., 10.), &mut rng);
+    let features = (0..features).map(|x| format!("feature_{}", x)).collect();
+    (array, features)
+}
+
+fn drift_benchmark(c: &mut Criterion) {
+    let monitor = Monitor::new();
+    let config = DriftConfig::try_new(
+        "name".to_string(),
+        "repo".to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    let mut group = c.benchmark_group("drift");
+    group.sample_size(10);
+
+    for num_features in [10, 100, 1000] {
+        let (array, features) = dataset(10_000, num_features);
+        let profile = monitor
+            .create_2d_drift_profile(&features, &array.view(), &config)
+            .unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("create_profile", num_features),
+            &array,
+            |b, array| {
+                b.iter(|| {
+                    monitor.create_2d_drift_profile(&features, black_box(&array.view()), &config)
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("compute_drift", num_features),
+            &array,
+            |b, array| {
+                b.iter(|| monitor.compute_drift(&features, black_box(&array.view()), &profile))
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("sample_data", num_features),
+            &array,
+            |b, array| {
+                b.iter(|| monitor.sample_data(&features, black_box(&array.view()), &profile))
+            },
+        );
+
+        let batches = array
+            .axis_chunks_iter(ndarray::Axis(0), 1000)
+            .collect::<Vec<_>>();
+        group.bench_with_input(
+            BenchmarkId::new("sample_batches", num_features),
+            &batches,
+            |b, batches| b.iter(|| monitor.sample_batches(&features, black_box(batches), &profile)),
+        );
+    }
+
+    group.finish();
+}
+
+fn record_benchmark(c: &mut Criterion) {
+    let records = (0..10_000)
+        .map(|i| {
+            DriftServerRecord::new(
+                "name".to_string(),
+                "repo".to_string(),
+                "0.1.0".to_string(),
+                format!("feature_{}", i % 100),
+                i as f64 / 7.0,
+            )
+        })
+        .collect::<Vec<_>>();
+    let json = serde_json::to_string(&records).unwrap();
+
+    let mut group = c.benchmark_group("records");
+    group.bench_function("serialize", |b| {
+        b.iter(|| serde_json::to_string(black_box(&records)))
+    });
+    group.bench_function("deserialize", |b| {
+        b.iter(|| serde_json::from_str::<Vec<DriftServerRecord>>(black_box(&json)))
+    });
+    group.bench_function("to_dict", |b| {
+        b.iter(|| {
+            black_box(&records)
+                .iter()
+                .map(|record| record.to_dict())
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, drift_benchmark, record_benchmark);
+criterion_main!(benches);
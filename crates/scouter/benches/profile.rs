@@ -13,7 +13,7 @@ fn criterion_benchmark(c: &mut Criterion) {
     let mut group = c.benchmark_group("sample-size-example");
     let array = Array::random((1000, 10), Uniform::new(0., 10.));
     let features: Vec<String> = (0..10).map(|x| x.to_string()).collect();
-    let config = DriftConfig::new(
+    let config = DriftConfig::try_new(
         "name".to_string(),
         "repo".to_string(),
         None,
@@ -21,12 +21,13 @@ fn criterion_benchmark(c: &mut Criterion) {
         None,
         None,
         None,
-    );
+    )
+    .unwrap();
     group.bench_function("monitor", |b| {
         b.iter(|| monitor.create_2d_drift_profile(&features, black_box(&array.view()), &config))
     });
     group.bench_function("profile", |b| {
-        b.iter(|| profiler.compute_stats(&features, black_box(&array.view()), &20))
+        b.iter(|| profiler.compute_stats(&features, black_box(&array.view()), &20, false, &[], &[]))
     });
     group.sample_size(10);
     group.finish();
@@ -272,6 +272,28 @@ impl Alerter {
         Ok(())
     }
 
+    // Checks EWMA and CUSUM drift values, which are scaled so that an absolute value >= 1
+    // is outside of the control limits
+    pub fn check_chart_rule_for_alert(
+        &mut self,
+        drift_array: &ArrayView1<f64>,
+        kind: AlertType,
+    ) -> Result<(), anyhow::Error> {
+        for (idx, value) in drift_array.iter().enumerate() {
+            if value.abs() >= 1.0 {
+                self.alerts.insert(Alert {
+                    zone: AlertZone::OutOfBounds.to_str(),
+                    kind: kind.to_str(),
+                });
+
+                self.insert_alert(4, idx, idx)
+                    .with_context(|| "Failed to insert alert")?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn update_alert(
         &mut self,
         start: usize,
@@ -358,14 +380,22 @@ pub fn generate_alert(
 ) -> Result<GeneratedAlert, anyhow::Error> {
     let mut alerter = Alerter::new();
 
-    if rule.process.is_some() {
+    if let Some(process) = &rule.process {
         alerter
-            .check_process_rule_for_alert(&drift_array.view(), &rule.process.as_ref().unwrap().rule)
+            .check_process_rule_for_alert(&drift_array.view(), &process.rule)
             .with_context(|| "Failed to check rule for alert")?;
 
         alerter
             .check_trend(&drift_array.view())
             .with_context(|| "Failed to check trend")?;
+    } else if rule.ewma.is_some() {
+        alerter
+            .check_chart_rule_for_alert(&drift_array.view(), AlertType::Ewma)
+            .with_context(|| "Failed to check rule for alert")?;
+    } else if rule.cusum.is_some() {
+        alerter
+            .check_chart_rule_for_alert(&drift_array.view(), AlertType::Cusum)
+            .with_context(|| "Failed to check rule for alert")?;
     } else {
         alerter
             .check_percentage_rule_for_alert(&drift_array.view())
@@ -407,7 +437,9 @@ pub fn generate_alerts(
 #[cfg(test)]
 mod tests {
 
-    use crate::utils::types::{AlertRule, PercentageAlertRule, ProcessAlertRule};
+    use crate::utils::types::{
+        AlertRule, CusumAlertRule, EwmaAlertRule, PercentageAlertRule, ProcessAlertRule,
+    };
 
     use super::*;
     use ndarray::arr2;
@@ -539,7 +571,7 @@ mod tests {
             "feature3".to_string(),
         ];
 
-        let rule = AlertRule::new(None, None);
+        let rule = AlertRule::new(None, None, None, None);
 
         let alerts = generate_alerts(&array.view(), features, rule).unwrap();
 
@@ -591,7 +623,7 @@ mod tests {
             "feature3".to_string(),
         ];
 
-        let rule = AlertRule::new(Some(PercentageAlertRule::new(None)), None);
+        let rule = AlertRule::new(Some(PercentageAlertRule::new(None)), None, None, None);
         let alerts = generate_alerts(&array.view(), features, rule).unwrap();
 
         let feature1 = alerts.features.get("feature1").unwrap();
@@ -610,4 +642,31 @@ mod tests {
         assert_eq!(feature2.alerts.len(), 0);
         assert_eq!(feature2.indices.len(), 0);
     }
+
+    #[test]
+    fn test_generate_chart_alerts() {
+        let array = arr2(&[[0.2, -0.4], [0.6, -0.9], [1.1, -1.2], [0.8, -0.5]]);
+
+        let features = vec!["feature1".to_string(), "feature2".to_string()];
+
+        let ewma = EwmaAlertRule::try_new(None, None).unwrap();
+        let rule = AlertRule::new(None, None, Some(ewma), None);
+        let alerts = generate_alerts(&array.view(), features.clone(), rule).unwrap();
+
+        let feature1 = alerts.features.get("feature1").unwrap();
+        assert_eq!(feature1.alerts.len(), 1);
+        assert_eq!(feature1.indices[&4], vec![vec![2, 2]]);
+        assert_eq!(feature1.alerts[0].kind, AlertType::Ewma.to_str());
+
+        // negative drift beyond the limit also alerts
+        let feature2 = alerts.features.get("feature2").unwrap();
+        assert_eq!(feature2.indices[&4], vec![vec![2, 2]]);
+
+        let cusum = CusumAlertRule::try_new(None, None).unwrap();
+        let rule = AlertRule::new(None, None, None, Some(cusum));
+        let alerts = generate_alerts(&array.view(), features, rule).unwrap();
+
+        let feature1 = alerts.features.get("feature1").unwrap();
+        assert_eq!(feature1.alerts[0].kind, AlertType::Cusum.to_str());
+    }
 }
@@ -1,3 +1,4 @@
 pub mod alert;
 pub mod monitor;
 pub mod profiler;
+pub mod sketch;
@@ -1,12 +1,19 @@
+use crate::core::alert::generate_alert;
+use crate::utils::migration::DRIFT_PROFILE_SCHEMA_VERSION;
 use crate::utils::types::DriftServerRecord;
-use crate::utils::types::{DriftConfig, DriftMap, DriftProfile, FeatureDrift, FeatureDriftProfile};
+use crate::utils::types::{
+    DriftConfig, DriftMap, DriftProfile, DriftScore, FeatureDrift, FeatureDriftProfile,
+};
 use anyhow::Ok;
 use anyhow::{Context, Result};
 use indicatif::ProgressBar;
 use ndarray::prelude::*;
 use ndarray::Axis;
 use num_traits::{Float, FromPrimitive, Num};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use rayon::prelude::*;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::fmt::Debug;
 pub struct Monitor {}
@@ -158,6 +165,10 @@ impl Monitor {
         Ok(DriftProfile {
             features: feat_profile,
             config: monitor_config.clone(),
+            feature_aliases: HashMap::new(),
+            feature_weights: HashMap::new(),
+            computed_features: HashMap::new(),
+            schema_version: DRIFT_PROFILE_SCHEMA_VERSION,
         })
     }
 
@@ -229,6 +240,73 @@ impl Monitor {
         Ok(drift_profile)
     }
 
+    /// Create a drift profile from columnar data without going through numpy
+    ///
+    /// # Arguments
+    ///
+    /// * `feature_map` - A map of feature names to column values. All columns must have the same length
+    /// * `monitor_config` - A monitor config
+    ///
+    /// # Returns
+    ///
+    /// A monitor profile
+    pub fn create_drift_profile_from_map<F>(
+        &self,
+        feature_map: &BTreeMap<String, Vec<F>>,
+        monitor_config: &DriftConfig,
+    ) -> Result<DriftProfile, anyhow::Error>
+    where
+        F: Float
+            + Sync
+            + FromPrimitive
+            + Send
+            + Num
+            + Debug
+            + num_traits::Zero
+            + ndarray::ScalarOperand,
+        F: Into<f64>,
+    {
+        let (features, array) = self
+            .feature_map_to_array(feature_map)
+            .with_context(|| "Failed to convert feature map to array")?;
+
+        self.create_2d_drift_profile(&features, &array.view(), monitor_config)
+    }
+
+    // Converts a map of feature columns into a 2D array with one column per feature
+    //
+    // # Arguments
+    //
+    // * `feature_map` - A map of feature names to column values
+    //
+    // # Returns
+    // A vector of feature names and a 2D array with columns in the same order
+    fn feature_map_to_array<F>(
+        &self,
+        feature_map: &BTreeMap<String, Vec<F>>,
+    ) -> Result<(Vec<String>, Array2<F>), anyhow::Error>
+    where
+        F: Float,
+    {
+        let rows = feature_map
+            .values()
+            .next()
+            .with_context(|| "Feature map is empty")?
+            .len();
+
+        if feature_map.values().any(|column| column.len() != rows) {
+            return Err(anyhow::anyhow!(
+                "All feature columns must have the same length"
+            ));
+        }
+
+        let features = feature_map.keys().cloned().collect::<Vec<_>>();
+        let columns = feature_map.values().collect::<Vec<_>>();
+        let array = Array2::from_shape_fn((rows, columns.len()), |(row, col)| columns[col][row]);
+
+        Ok((features, array))
+    }
+
     // Samples data for drift detection
     //
     // # Arguments
@@ -373,43 +451,16 @@ impl Monitor {
         F: Into<f64>,
     {
         let num_features = drift_profile.features.len();
+        let features = &drift_profile.resolve_features(features);
 
         // iterate through each feature
         let sample_data = self
             ._sample_data(array, drift_profile.config.sample_size, num_features)
             .with_context(|| "Failed to create sample data")?;
 
-        // iterate through each row of samples
-        let drift_array = sample_data
-            .axis_iter(Axis(0))
-            .into_par_iter()
-            .map(|x| {
-                // match AlertRules enum
-
-                let drift = if drift_profile.config.alert_rule.process.is_some() {
-                    self.set_control_drift_value(x, num_features, drift_profile, features)
-                        .unwrap()
-                } else {
-                    let rule = drift_profile
-                        .config
-                        .alert_rule
-                        .percentage
-                        .as_ref()
-                        .unwrap()
-                        .rule;
-
-                    self.set_percentage_drift_value(x, num_features, drift_profile, features, rule)
-                        .unwrap()
-                };
-
-                drift
-            })
-            .collect::<Vec<_>>();
-
-        // convert drift array to 2D array
-        let drift_array =
-            Array::from_shape_vec((drift_array.len(), num_features), drift_array.concat())
-                .with_context(|| "Failed to create 2D array")?;
+        let drift_array = self
+            .calculate_drift_from_sample(features, &sample_data.view(), drift_profile)
+            .with_context(|| "Failed to calculate drift")?;
 
         let mut drift_map = DriftMap::new(
             drift_profile.config.name.clone(),
@@ -458,6 +509,7 @@ impl Monitor {
         F: Into<f64>,
     {
         let num_features = drift_profile.features.len();
+        let features = &drift_profile.resolve_features(features);
 
         // iterate through each feature
         let sample_data = self
@@ -486,14 +538,218 @@ impl Monitor {
         Ok(records)
     }
 
+    // Samples multiple batches of data in parallel (e.g. record batches from a large backfill)
+    //
+    // Each batch is sampled up to its last complete sample window. The rows left over at the end
+    // of each batch are carried over and sampled together after all batches, so only the final
+    // sample of a call can be smaller than the profile's sample size.
+    //
+    // # Arguments
+    //
+    // * `features` - A vector of feature names that is mapped to each array
+    // * `arrays` - A slice of 2D arrays sharing the same features
+    // * `drift_profile` - A monitor profile
+    //
+    pub fn sample_batches<F>(
+        &self,
+        features: &[String],
+        arrays: &[ArrayView2<F>],
+        drift_profile: &DriftProfile,
+    ) -> Result<Vec<DriftServerRecord>, anyhow::Error>
+    where
+        F: Float
+            + Sync
+            + FromPrimitive
+            + Send
+            + Num
+            + Debug
+            + num_traits::Zero
+            + ndarray::ScalarOperand,
+        F: Into<f64>,
+    {
+        let sample_size = drift_profile.config.sample_size.max(1);
+        let (complete, remainders): (Vec<_>, Vec<_>) = arrays
+            .iter()
+            .map(|array| {
+                let split = array.nrows() - array.nrows() % sample_size;
+                (array.slice(s![..split, ..]), array.slice(s![split.., ..]))
+            })
+            .unzip();
+
+        let mut records = complete
+            .par_iter()
+            .filter(|array| !array.is_empty())
+            .map(|array| self.sample_data(features, array, drift_profile))
+            .collect::<Result<Vec<_>, _>>()
+            .with_context(|| "Failed to sample batches")?;
+
+        if remainders.iter().any(|array| !array.is_empty()) {
+            let remainder = ndarray::concatenate(Axis(0), &remainders)
+                .with_context(|| "Failed to combine remaining batch rows")?;
+
+            records.push(
+                self.sample_data(features, &remainder.view(), drift_profile)
+                    .with_context(|| "Failed to sample remaining batch rows")?,
+            );
+        }
+
+        Ok(records.concat())
+    }
+
+    // Aggregates feature drift into a single model-level drift score between 0 and 1
+    //
+    // Each feature's drift is scaled by the largest drift value of the profile's alert rule
+    // (4 for process rules, 1 for percentage, EWMA and CUSUM rules) and weighted by the profile's feature weights.
+    // Features without a weight are ignored when weights are set, otherwise all features are
+    // weighted equally.
+    //
+    // # Arguments
+    //
+    // * `drift_map` - A drift map computed from the profile
+    // * `drift_profile` - A monitor profile
+    //
+    pub fn compute_drift_score(
+        &self,
+        drift_map: &DriftMap,
+        drift_profile: &DriftProfile,
+    ) -> Result<DriftScore, anyhow::Error> {
+        let max_drift = if drift_profile.config.alert_rule.process.is_some() {
+            4.0
+        } else {
+            1.0
+        };
+
+        let weights = drift_map
+            .features
+            .keys()
+            .map(|feature| {
+                let weight = if drift_profile.feature_weights.is_empty() {
+                    1.0
+                } else {
+                    *drift_profile.feature_weights.get(feature).unwrap_or(&0.0)
+                };
+                weight.max(0.0)
+            })
+            .collect::<Vec<_>>();
+
+        let total_weight: f64 = weights.iter().sum();
+        if total_weight == 0.0 {
+            return Err(anyhow::anyhow!(
+                "Feature weights must contain a positive weight for at least one drift map feature"
+            ));
+        }
+
+        let num_samples = drift_map
+            .features
+            .values()
+            .map(|feature_drift| feature_drift.drift.len())
+            .max()
+            .unwrap_or(0);
+
+        let mut trend = vec![0.0; num_samples];
+        for (feature_drift, weight) in drift_map.features.values().zip(weights.iter()) {
+            for (i, drift) in feature_drift.drift.iter().enumerate() {
+                trend[i] += weight * (drift.abs() / max_drift).min(1.0) / total_weight;
+            }
+        }
+
+        let score = if trend.is_empty() {
+            0.0
+        } else {
+            trend.iter().sum::<f64>() / trend.len() as f64
+        };
+
+        Ok(DriftScore { score, trend })
+    }
+
+    // Computes EWMA or CUSUM drift values for each feature over a sequence of samples
+    //
+    // Sample means are standardized with the profile's center and one sigma limit. Drift values
+    // are the chart statistic scaled by its control limit, so an absolute value >= 1 is out of control.
+    // The chart state starts fresh on every call and is not carried over between calls.
+    //
+    // # Arguments
+    //
+    // * `sample_array` - A 2D array of sample means in sample order
+    // * `drift_profile` - A monitor profile with an EWMA or CUSUM alert rule
+    // * `features` - Profile feature names mapped to the array columns
+    //
+    fn set_chart_drift_values(
+        &self,
+        sample_array: &ArrayView2<f64>,
+        drift_profile: &DriftProfile,
+        features: &[String],
+    ) -> Result<Array2<f64>, anyhow::Error> {
+        let rule = &drift_profile.config.alert_rule;
+        let mut drift_array = Array2::<f64>::zeros((sample_array.nrows(), features.len()));
+
+        for (i, feature) in features.iter().enumerate() {
+            let feature_profile = match drift_profile.features.get(feature) {
+                Some(feature_profile) => feature_profile,
+                None => continue,
+            };
+
+            let sigma = (feature_profile.one_ucl - feature_profile.center).max(f64::EPSILON);
+            let standardized = sample_array
+                .column(i)
+                .mapv(|value| (value - feature_profile.center) / sigma);
+
+            let drift = if let Some(ewma) = &rule.ewma {
+                let mut statistic = 0.0;
+                let decay = 1.0 - ewma.lambda;
+
+                standardized
+                    .iter()
+                    .enumerate()
+                    .map(|(t, x)| {
+                        statistic = ewma.lambda * x + decay * statistic;
+                        let variance = ewma.lambda / (2.0 - ewma.lambda)
+                            * (1.0 - decay.powi(2 * (t as i32 + 1)));
+                        statistic / (ewma.limit * variance.sqrt())
+                    })
+                    .collect::<Vec<_>>()
+            } else {
+                let cusum = rule
+                    .cusum
+                    .as_ref()
+                    .with_context(|| "Expected an EWMA or CUSUM alert rule")?;
+                let (mut upper, mut lower) = (0.0_f64, 0.0_f64);
+
+                standardized
+                    .iter()
+                    .map(|x| {
+                        upper = (upper + x - cusum.k).max(0.0);
+                        lower = (lower - x - cusum.k).max(0.0);
+                        if upper >= lower {
+                            upper / cusum.h
+                        } else {
+                            -lower / cusum.h
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            };
+
+            drift_array.column_mut(i).assign(&Array1::from_vec(drift));
+        }
+
+        Ok(drift_array)
+    }
+
     pub fn calculate_drift_from_sample(
         &self,
         features: &[String],
         sample_array: &ArrayView2<f64>, // n x m data array (features and predictions)
         drift_profile: &DriftProfile,
     ) -> Result<Array2<f64>, anyhow::Error> {
-        // iterate through each row of samples
         let num_features = drift_profile.features.len();
+        let features = &drift_profile.resolve_features(features);
+
+        // chart rules depend on previous samples
+        if drift_profile.config.alert_rule.is_chart_rule() {
+            return self.set_chart_drift_values(sample_array, drift_profile, features);
+        }
+
+        // iterate through each row of samples
         let drift_array = sample_array
             .axis_iter(Axis(0))
             .into_par_iter()
@@ -527,6 +783,115 @@ impl Monitor {
 
         Ok(drift_array)
     }
+
+    // Estimates the expected false positive alert rate of a profile by bootstrapping monitoring
+    // windows from baseline data (typically the data the profile was created from)
+    //
+    // Each window draws `window_size` samples of `sample_size` rows with replacement, computes
+    // drift against the profile and checks the profile's alert rule. The false positive rate of a
+    // feature is the fraction of windows with at least one alert for that feature.
+    //
+    // # Arguments
+    //
+    // * `features` - A vector of feature names that is mapped to the array
+    // * `array` - A 2D array of baseline data
+    // * `drift_profile` - A monitor profile
+    // * `num_windows` - The number of bootstrapped windows
+    // * `window_size` - The number of samples in each window (e.g. samples per scheduled run)
+    // * `seed` - Optional seed for reproducible estimates
+    //
+    pub fn estimate_false_positive_rate<F>(
+        &self,
+        features: &[String],
+        array: &ArrayView2<F>,
+        drift_profile: &DriftProfile,
+        num_windows: usize,
+        window_size: usize,
+        seed: Option<u64>,
+    ) -> Result<HashMap<String, f64>, anyhow::Error>
+    where
+        F: Float
+            + Sync
+            + FromPrimitive
+            + Send
+            + Num
+            + Debug
+            + num_traits::Zero
+            + ndarray::ScalarOperand,
+        F: Into<f64>,
+    {
+        let num_rows = array.nrows();
+        let num_features = drift_profile.features.len();
+        let sample_size = drift_profile.config.sample_size.max(1);
+
+        if num_rows == 0 || num_windows == 0 || window_size == 0 {
+            return Err(anyhow::anyhow!(
+                "Baseline data, number of windows and window size must be non-empty"
+            ));
+        }
+
+        if array.ncols() != num_features || features.len() != num_features {
+            return Err(anyhow::anyhow!(
+                "Expected {} features to match the drift profile, got {} feature names and {} columns",
+                num_features,
+                features.len(),
+                array.ncols()
+            ));
+        }
+
+        let resolved = drift_profile.resolve_features(features);
+        let seed = seed.unwrap_or_else(rand::random);
+
+        // each window is seeded separately so estimates are reproducible in parallel
+        let window_alerts = (0..num_windows)
+            .into_par_iter()
+            .map(|window| {
+                let mut rng = StdRng::seed_from_u64(seed.wrapping_add(window as u64));
+
+                let samples = (0..window_size)
+                    .flat_map(|_| {
+                        let rows = (0..sample_size)
+                            .map(|_| rng.gen_range(0..num_rows))
+                            .collect::<Vec<_>>();
+
+                        array
+                            .select(Axis(0), &rows)
+                            .mean_axis(Axis(0))
+                            .unwrap()
+                            .mapv(|x| x.into())
+                            .to_vec()
+                    })
+                    .collect::<Vec<f64>>();
+
+                let samples = Array::from_shape_vec((window_size, num_features), samples)
+                    .with_context(|| "Failed to create 2D array")?;
+
+                let drift = self
+                    .calculate_drift_from_sample(features, &samples.view(), drift_profile)
+                    .with_context(|| "Failed to calculate drift")?;
+
+                drift
+                    .axis_iter(Axis(1))
+                    .map(|column| {
+                        let (alerts, _) = generate_alert(&column, &drift_profile.config.alert_rule)
+                            .with_context(|| "Failed to check rule for alert")?;
+                        Ok(!alerts.is_empty())
+                    })
+                    .collect::<Result<Vec<bool>, anyhow::Error>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let rates = resolved
+            .into_iter()
+            .enumerate()
+            .map(|(i, feature)| {
+                let alerted = window_alerts.iter().filter(|alerts| alerts[i]).count();
+                (feature, alerted as f64 / num_windows as f64)
+            })
+            .collect();
+
+        Ok(rates)
+    }
 }
 
 // convert drift array to 2D array
@@ -540,7 +905,7 @@ impl Default for Monitor {
 #[cfg(test)]
 mod tests {
 
-    use crate::utils::types::{AlertRule, PercentageAlertRule};
+    use crate::utils::types::{AlertRule, CusumAlertRule, EwmaAlertRule, PercentageAlertRule};
 
     use super::*;
     use approx::relative_eq;
@@ -562,7 +927,7 @@ mod tests {
         ];
 
         let monitor = Monitor::new();
-        let config = DriftConfig::new(
+        let config = DriftConfig::try_new(
             "name".to_string(),
             "repo".to_string(),
             None,
@@ -570,7 +935,8 @@ mod tests {
             None,
             None,
             None,
-        );
+        )
+        .unwrap();
 
         let profile = monitor
             .create_2d_drift_profile(&features, &array.view(), &config)
@@ -597,7 +963,7 @@ mod tests {
         ];
 
         let monitor = Monitor::new();
-        let config = DriftConfig::new(
+        let config = DriftConfig::try_new(
             "name".to_string(),
             "repo".to_string(),
             None,
@@ -605,7 +971,8 @@ mod tests {
             None,
             None,
             None,
-        );
+        )
+        .unwrap();
 
         let profile = monitor
             .create_2d_drift_profile(&features, &array.view(), &config)
@@ -613,6 +980,95 @@ mod tests {
         assert_eq!(profile.features.len(), 3);
     }
 
+    #[test]
+    fn test_feature_aliases() {
+        let array = Array::random((100, 2), Uniform::new(0., 10.));
+        let features = vec!["feature_1".to_string(), "feature_2".to_string()];
+        let config = DriftConfig::try_new(
+            "name".to_string(),
+            "repo".to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let mut profile = Monitor::new()
+            .create_2d_drift_profile(&features, &array.view(), &config)
+            .unwrap();
+
+        profile
+            .try_add_feature_alias("renamed".to_string(), "feature_1".to_string())
+            .unwrap();
+
+        // targets must be profile features, so an alias can not point at another alias
+        assert!(profile
+            .try_add_feature_alias("renamed_again".to_string(), "renamed".to_string())
+            .is_err());
+
+        // aliases can not shadow a profile feature
+        assert!(profile
+            .try_add_feature_alias("feature_2".to_string(), "feature_1".to_string())
+            .is_err());
+
+        assert_eq!(
+            profile.resolve_features(&["renamed".to_string(), "feature_2".to_string()]),
+            features
+        );
+    }
+
+    #[test]
+    fn test_create_drift_profile_from_map() {
+        let array = Array::random((1030, 3), Uniform::new(0., 10.));
+
+        let mut feature_map = BTreeMap::new();
+        for (i, column) in array.columns().into_iter().enumerate() {
+            feature_map.insert(format!("feature_{}", i + 1), column.to_vec());
+        }
+
+        let config = DriftConfig::try_new(
+            "name".to_string(),
+            "repo".to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let monitor = Monitor::new();
+
+        let profile = monitor
+            .create_drift_profile_from_map(&feature_map, &config)
+            .unwrap();
+        assert_eq!(profile.features.len(), 3);
+
+        let features = vec![
+            "feature_1".to_string(),
+            "feature_2".to_string(),
+            "feature_3".to_string(),
+        ];
+        let array_profile = monitor
+            .create_2d_drift_profile(&features, &array.view(), &config)
+            .unwrap();
+
+        for feature in features.iter() {
+            assert!(relative_eq!(
+                profile.features[feature].center,
+                array_profile.features[feature].center
+            ));
+        }
+
+        // columns of different lengths should fail
+        feature_map.insert("feature_4".to_string(), vec![1.0; 10]);
+        assert!(monitor
+            .create_drift_profile_from_map(&feature_map, &config)
+            .is_err());
+    }
+
     #[test]
     fn test_drift_detect_process() {
         // create 2d array
@@ -624,7 +1080,7 @@ mod tests {
             "feature_3".to_string(),
         ];
 
-        let config = DriftConfig::new(
+        let config = DriftConfig::try_new(
             "name".to_string(),
             "repo".to_string(),
             None,
@@ -632,7 +1088,8 @@ mod tests {
             None,
             None,
             None,
-        );
+        )
+        .unwrap();
 
         let monitor = Monitor::new();
 
@@ -659,6 +1116,288 @@ mod tests {
         // create server records
     }
 
+    #[test]
+    fn test_drift_detect_charts() {
+        let array = Array::random((1000, 3), Uniform::new(0., 10.));
+
+        let features = vec![
+            "feature_1".to_string(),
+            "feature_2".to_string(),
+            "feature_3".to_string(),
+        ];
+
+        // sustained shift of feature_2 below the zone 2 limit (about 1.7 sigma)
+        let mut shifted = Array::random((500, 3), Uniform::new(0., 10.));
+        shifted.column_mut(1).mapv_inplace(|x| x + 5.0);
+
+        let monitor = Monitor::new();
+
+        for rule in [
+            AlertRule::new(
+                None,
+                None,
+                Some(EwmaAlertRule::try_new(None, None).unwrap()),
+                None,
+            ),
+            AlertRule::new(
+                None,
+                None,
+                None,
+                Some(CusumAlertRule::try_new(None, None).unwrap()),
+            ),
+        ] {
+            let config = DriftConfig::try_new(
+                "name".to_string(),
+                "repo".to_string(),
+                None,
+                None,
+                Some(25),
+                None,
+                Some(rule.clone()),
+            )
+            .unwrap();
+
+            let profile = monitor
+                .create_2d_drift_profile(&features, &array.view(), &config)
+                .unwrap();
+
+            let drift_map = monitor
+                .compute_drift(&features, &shifted.view(), &profile)
+                .unwrap();
+
+            let drift = &drift_map.features.get("feature_2").unwrap().drift;
+            assert_eq!(drift.len(), 20);
+            assert!(drift.iter().any(|value| *value >= 1.0));
+
+            let (alerts, _) =
+                generate_alert(&Array1::from_vec(drift.clone()).view(), &rule).unwrap();
+            assert_eq!(alerts.len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_sample_batches() {
+        let array = Array::random((1030, 3), Uniform::new(0., 10.));
+
+        let features = vec![
+            "feature_1".to_string(),
+            "feature_2".to_string(),
+            "feature_3".to_string(),
+        ];
+
+        let config = DriftConfig::try_new(
+            "name".to_string(),
+            "repo".to_string(),
+            None,
+            None,
+            Some(25),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let monitor = Monitor::new();
+
+        let profile = monitor
+            .create_2d_drift_profile(&features, &array.view(), &config)
+            .unwrap();
+
+        let batches = vec![
+            array.slice(s![0..510, ..]),
+            array.slice(s![510..510, ..]),
+            array.slice(s![510..520, ..]),
+            array.slice(s![520.., ..]),
+        ];
+
+        let records = monitor
+            .sample_batches(&features, &batches, &profile)
+            .unwrap();
+
+        // 20 full samples from the first and last batch, and 2 from the 30 leftover rows
+        // (the same as sampling all 1030 rows at once), for each feature
+        assert_eq!(records.len(), (20 + 20 + 2) * 3);
+    }
+
+    #[test]
+    fn test_estimate_false_positive_rate() {
+        let array = Array::random((1000, 3), Uniform::new(0., 10.));
+
+        let features = vec![
+            "feature_1".to_string(),
+            "feature_2".to_string(),
+            "feature_3".to_string(),
+        ];
+
+        let config = DriftConfig::try_new(
+            "name".to_string(),
+            "repo".to_string(),
+            None,
+            None,
+            Some(25),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let monitor = Monitor::new();
+
+        let profile = monitor
+            .create_2d_drift_profile(&features, &array.view(), &config)
+            .unwrap();
+
+        let rates = monitor
+            .estimate_false_positive_rate(&features, &array.view(), &profile, 50, 10, Some(42))
+            .unwrap();
+
+        assert_eq!(rates.len(), 3);
+        assert!(rates.values().all(|rate| (0.0..=1.0).contains(rate)));
+
+        // same seed, same estimate
+        let repeated = monitor
+            .estimate_false_positive_rate(&features, &array.view(), &profile, 50, 10, Some(42))
+            .unwrap();
+        assert_eq!(rates, repeated);
+
+        // shifted baseline alerts in every window
+        let shifted = array.mapv(|x| x + 10.0);
+        let rates = monitor
+            .estimate_false_positive_rate(&features, &shifted.view(), &profile, 10, 10, Some(42))
+            .unwrap();
+        assert!(rates.values().all(|rate| *rate == 1.0));
+
+        assert!(monitor
+            .estimate_false_positive_rate(&features, &array.view(), &profile, 0, 10, None)
+            .is_err());
+
+        // mismatched feature names are rejected instead of panicking
+        assert!(monitor
+            .estimate_false_positive_rate(&features[..2], &array.view(), &profile, 10, 10, None)
+            .is_err());
+
+        // a sample size of 0 samples single rows, like sample_batches
+        let mut profile = profile;
+        profile.config.sample_size = 0;
+        let rates = monitor
+            .estimate_false_positive_rate(&features, &array.view(), &profile, 10, 10, Some(42))
+            .unwrap();
+        assert_eq!(rates.len(), 3);
+    }
+
+    #[test]
+    fn test_compute_drift_score() {
+        let config = DriftConfig::try_new(
+            "name".to_string(),
+            "repo".to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let mut drift_map =
+            DriftMap::new("name".to_string(), "repo".to_string(), "0.1.0".to_string());
+        drift_map.add_feature(
+            "feature_1".to_string(),
+            FeatureDrift {
+                samples: vec![0.0, 0.0],
+                drift: vec![0.0, 4.0],
+            },
+        );
+        drift_map.add_feature(
+            "feature_2".to_string(),
+            FeatureDrift {
+                samples: vec![0.0, 0.0],
+                drift: vec![-2.0, 0.0],
+            },
+        );
+
+        let mut profile = DriftProfile {
+            features: HashMap::new(),
+            config,
+            feature_aliases: HashMap::new(),
+            feature_weights: HashMap::new(),
+            computed_features: HashMap::new(),
+            schema_version: DRIFT_PROFILE_SCHEMA_VERSION,
+        };
+
+        let monitor = Monitor::new();
+
+        // equal weights
+        let score = monitor.compute_drift_score(&drift_map, &profile).unwrap();
+        assert_eq!(score.trend, vec![0.25, 0.5]);
+        assert_eq!(score.score, 0.375);
+
+        // only feature_1 is weighted
+        profile.feature_weights.insert("feature_1".to_string(), 3.0);
+        let score = monitor.compute_drift_score(&drift_map, &profile).unwrap();
+        assert_eq!(score.trend, vec![0.0, 1.0]);
+
+        profile.feature_weights = HashMap::from([("missing".to_string(), 1.0)]);
+        assert!(monitor.compute_drift_score(&drift_map, &profile).is_err());
+    }
+
+    #[test]
+    fn test_drift_feature_aliases() {
+        let array = Array::random((1030, 3), Uniform::new(0., 10.));
+
+        let features = vec![
+            "feature_1".to_string(),
+            "feature_2".to_string(),
+            "feature_3".to_string(),
+        ];
+
+        let config = DriftConfig::try_new(
+            "name".to_string(),
+            "repo".to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let monitor = Monitor::new();
+
+        let mut profile = monitor
+            .create_2d_drift_profile(&features, &array.view(), &config)
+            .unwrap();
+
+        profile
+            .feature_aliases
+            .insert("renamed_2".to_string(), "feature_2".to_string());
+
+        // feature_2 was renamed upstream
+        let renamed = vec![
+            "feature_1".to_string(),
+            "renamed_2".to_string(),
+            "feature_3".to_string(),
+        ];
+
+        let drift_map = monitor
+            .compute_drift(&renamed, &array.view(), &profile)
+            .unwrap();
+        assert!(drift_map.features.contains_key("feature_2"));
+        assert!(!drift_map.features.contains_key("renamed_2"));
+
+        let records = monitor
+            .sample_data(&renamed, &array.view(), &profile)
+            .unwrap();
+        assert!(records.iter().all(|record| record.feature != "renamed_2"));
+
+        // aliases survive serialization and older profiles without aliases still load
+        let loaded = DriftProfile::load_from_json(profile.model_dump_json());
+        assert_eq!(loaded.feature_aliases["renamed_2"], "feature_2");
+
+        let mut value: serde_json::Value =
+            serde_json::from_str(&profile.model_dump_json()).unwrap();
+        value.as_object_mut().unwrap().remove("feature_aliases");
+        let loaded = DriftProfile::load_from_json(value.to_string());
+        assert!(loaded.feature_aliases.is_empty());
+    }
+
     #[test]
     fn test_sample_data() {
         // create 2d array
@@ -670,7 +1409,7 @@ mod tests {
             "feature_3".to_string(),
         ];
 
-        let config = DriftConfig::new(
+        let config = DriftConfig::try_new(
             "name".to_string(),
             "repo".to_string(),
             None,
@@ -678,7 +1417,8 @@ mod tests {
             None,
             None,
             None,
-        );
+        )
+        .unwrap();
 
         let monitor = Monitor::new();
 
@@ -706,7 +1446,7 @@ mod tests {
             "feature_3".to_string(),
         ];
 
-        let config = DriftConfig::new(
+        let config = DriftConfig::try_new(
             "name".to_string(),
             "repo".to_string(),
             None,
@@ -714,7 +1454,8 @@ mod tests {
             None,
             None,
             None,
-        );
+        )
+        .unwrap();
 
         let monitor = Monitor::new();
 
@@ -747,7 +1488,7 @@ mod tests {
             "feature_3".to_string(),
         ];
 
-        let config = DriftConfig::new(
+        let config = DriftConfig::try_new(
             "name".to_string(),
             "repo".to_string(),
             None,
@@ -757,8 +1498,11 @@ mod tests {
             Some(AlertRule {
                 process: None,
                 percentage: Some(PercentageAlertRule { rule: 0.1 }),
+                ewma: None,
+                cusum: None,
             }),
-        );
+        )
+        .unwrap();
 
         let monitor = Monitor::new();
 
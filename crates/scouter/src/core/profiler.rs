@@ -1,4 +1,8 @@
-use crate::utils::types::{DataProfile, Distinct, FeatureDataProfile, Histogram, Quantiles};
+use crate::core::sketch::{top_k_counts, HyperLogLog, SpaceSaving};
+use crate::utils::migration::DATA_PROFILE_SCHEMA_VERSION;
+use crate::utils::types::{
+    BooleanStats, DataProfile, Distinct, FeatureDataProfile, Histogram, Quantiles, TemporalStats,
+};
 use anyhow::{Context, Result};
 use ndarray::prelude::*;
 use ndarray::Axis;
@@ -10,7 +14,18 @@ use num_traits::{Float, FromPrimitive, Num};
 use rayon::prelude::*;
 use std::cmp::Ord;
 use std::collections::HashMap;
-use std::collections::HashSet;
+
+// number of most frequent values to keep per feature
+const TOP_K: usize = 10;
+
+// HyperLogLog precision used for approximate distinct counts (~0.8% standard error)
+const HLL_PRECISION: u8 = 14;
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+const SECONDS_PER_HOUR: f64 = 3_600.0;
+
+// 1970-01-01 was a Thursday, 3 days after Monday
+const EPOCH_WEEKDAY: i64 = 3;
 
 pub struct Profiler {}
 
@@ -132,21 +147,44 @@ impl Profiler {
     /// # Arguments
     ///
     /// * `array` - A 2D array of values.
+    /// * `approx` - Whether to estimate distinct counts and top values with sketches
+    ///   (HyperLogLog and space-saving) instead of exact counting.
     ///
     /// # Returns
     ///
     /// A 1D array of values.
-    pub fn compute_distinct<F>(&self, array: &ArrayView2<F>) -> Result<Vec<Distinct>>
+    pub fn compute_distinct<F>(&self, array: &ArrayView2<F>, approx: bool) -> Result<Vec<Distinct>>
     where
-        F: std::fmt::Display + Num,
+        F: std::fmt::Display + Num + Sync + Send,
     {
         let unique: Vec<Distinct> = array
             .axis_iter(Axis(1))
+            .into_par_iter()
             .map(|x| {
-                let hash = x.iter().map(|x| x.to_string()).collect::<HashSet<String>>();
+                let (count, top_values) = if approx {
+                    let mut hll = HyperLogLog::new(HLL_PRECISION);
+                    let mut heavy_hitters = SpaceSaving::new(TOP_K * 10);
+
+                    x.iter().for_each(|value| {
+                        let value = value.to_string();
+                        hll.insert(&value);
+                        heavy_hitters.insert(value);
+                    });
+
+                    (hll.count(), heavy_hitters.top_k(TOP_K))
+                } else {
+                    let mut counts = HashMap::new();
+                    x.iter().for_each(|value| {
+                        *counts.entry(value.to_string()).or_insert(0) += 1;
+                    });
+
+                    (counts.len(), top_k_counts(&counts, TOP_K))
+                };
+
                 Distinct {
-                    count: hash.len(),
-                    percent: hash.len() as f64 / x.len() as f64,
+                    count,
+                    percent: count as f64 / x.len() as f64,
+                    top_values,
                 }
             })
             .collect();
@@ -154,6 +192,67 @@ impl Profiler {
         Ok(unique)
     }
 
+    /// Count the true, false and null values of a boolean feature encoded as 0/1
+    ///
+    /// # Arguments
+    ///
+    /// * `array` - A 1D array of values where NaN is null and any other non-zero value is true
+    ///
+    /// # Returns
+    ///
+    /// The true, false and null counts
+    pub fn compute_boolean_stats<F>(&self, array: &ArrayView1<F>) -> BooleanStats
+    where
+        F: Float,
+    {
+        let null_count = array.iter().filter(|value| value.is_nan()).count();
+        let true_count = array
+            .iter()
+            .filter(|value| !value.is_nan() && !value.is_zero())
+            .count();
+
+        BooleanStats {
+            true_count,
+            false_count: array.len() - true_count - null_count,
+            null_count,
+        }
+    }
+
+    /// Compute the weekday and hour distribution of a datetime feature encoded as seconds since epoch
+    ///
+    /// # Arguments
+    ///
+    /// * `array` - A 1D array of UTC timestamps in seconds since epoch, where NaN is missing
+    ///
+    /// # Returns
+    ///
+    /// Counts per day of the week (starting on Monday) and per hour of the day
+    pub fn compute_temporal_stats<F>(&self, array: &ArrayView1<F>) -> TemporalStats
+    where
+        F: Float,
+        f64: From<F>,
+    {
+        let mut weekday_counts = vec![0; 7];
+        let mut hour_counts = vec![0; 24];
+
+        array
+            .iter()
+            .filter(|value| !value.is_nan())
+            .for_each(|value| {
+                let seconds = f64::from(*value);
+                let days = (seconds / SECONDS_PER_DAY).floor() as i64;
+                let hour = (seconds.rem_euclid(SECONDS_PER_DAY) / SECONDS_PER_HOUR) as usize;
+
+                weekday_counts[(days + EPOCH_WEEKDAY).rem_euclid(7) as usize] += 1;
+                hour_counts[hour.min(23)] += 1;
+            });
+
+        TemporalStats {
+            weekday_counts,
+            hour_counts,
+        }
+    }
+
     /// Compute the histogram and bins from a 2D matrix.
     ///
     /// # Arguments
@@ -281,6 +380,12 @@ impl Profiler {
     /// # Arguments
     ///
     /// * `array` - A 1D array of f64 values
+    /// * `approx_distinct` - Whether to use approximate distinct counting for high-cardinality data
+    /// * `boolean_features` - Features holding booleans encoded as 0/1, which also get true/false/null counts
+    /// * `datetime_features` - Features holding seconds since epoch, which also get weekday and hour counts
+    ///
+    /// Boolean and datetime features may contain missing values (NaN), which are counted and
+    /// left out of their other stats. Other features must not contain missing values.
     ///  
     /// # Returns
     ///
@@ -290,7 +395,124 @@ impl Profiler {
         features: &[String],
         array: &ArrayView2<F>,
         bin_size: &usize,
+        approx_distinct: bool,
+        boolean_features: &[String],
+        datetime_features: &[String],
     ) -> Result<DataProfile, anyhow::Error>
+    where
+        F: Float
+            + MaybeNan
+            + FromPrimitive
+            + std::fmt::Display
+            + Sync
+            + Send
+            + Num
+            + Clone
+            + std::fmt::Debug,
+        F: Into<f64>,
+        <F as MaybeNan>::NotNan: Ord,
+        f64: From<F>,
+        <F as MaybeNan>::NotNan: Clone,
+    {
+        let missing_counts = array
+            .axis_iter(Axis(1))
+            .into_par_iter()
+            .map(|column| column.iter().filter(|value| value.is_nan()).count())
+            .collect::<Vec<_>>();
+
+        for (feature, missing) in features.iter().zip(&missing_counts) {
+            if *missing > 0
+                && !boolean_features.contains(feature)
+                && !datetime_features.contains(feature)
+            {
+                return Err(anyhow::anyhow!(
+                    "Feature {} contains {} missing values. Only boolean and datetime features may contain missing values",
+                    feature,
+                    missing
+                ));
+            }
+        }
+
+        let mut profiles = if missing_counts.iter().all(|missing| *missing == 0) {
+            self.compute_feature_profiles(features, array, bin_size, approx_distinct)?
+        } else {
+            // features with missing values are profiled one at a time on their non-missing values
+            let complete = (0..features.len())
+                .filter(|i| missing_counts[*i] == 0)
+                .collect::<Vec<_>>();
+
+            let mut profiles = if complete.is_empty() {
+                HashMap::new()
+            } else {
+                let complete_features = complete
+                    .iter()
+                    .map(|i| features[*i].clone())
+                    .collect::<Vec<_>>();
+
+                self.compute_feature_profiles(
+                    &complete_features,
+                    &array.select(Axis(1), &complete).view(),
+                    bin_size,
+                    approx_distinct,
+                )?
+            };
+
+            for i in (0..features.len()).filter(|i| missing_counts[*i] > 0) {
+                let values = array
+                    .column(i)
+                    .iter()
+                    .copied()
+                    .filter(|value| !value.is_nan())
+                    .collect::<Array1<F>>();
+
+                if values.is_empty() {
+                    return Err(anyhow::anyhow!(
+                        "Feature {} only contains missing values",
+                        features[i]
+                    ));
+                }
+
+                profiles.extend(self.compute_feature_profiles(
+                    &features[i..=i],
+                    &values.insert_axis(Axis(1)).view(),
+                    bin_size,
+                    approx_distinct,
+                )?);
+            }
+
+            profiles
+        };
+
+        for (i, feature) in features.iter().enumerate() {
+            let column = array.column(i);
+            let profile = profiles
+                .get_mut(feature)
+                .with_context(|| format!("Missing profile for feature {}", feature))?;
+
+            profile.missing_count = missing_counts[i];
+            profile.missing_rate = missing_counts[i] as f64 / column.len().max(1) as f64;
+            profile.boolean = boolean_features
+                .contains(feature)
+                .then(|| self.compute_boolean_stats(&column));
+            profile.temporal = datetime_features
+                .contains(feature)
+                .then(|| self.compute_temporal_stats(&column));
+        }
+
+        Ok(DataProfile {
+            features: profiles,
+            schema_version: DATA_PROFILE_SCHEMA_VERSION,
+        })
+    }
+
+    /// Compute the base stats of features without missing values
+    fn compute_feature_profiles<F>(
+        &self,
+        features: &[String],
+        array: &ArrayView2<F>,
+        bin_size: &usize,
+        approx_distinct: bool,
+    ) -> Result<HashMap<String, FeatureDataProfile>>
     where
         F: Float
             + MaybeNan
@@ -322,7 +544,7 @@ impl Profiler {
             .compute_max(array)
             .with_context(|| "Failed to compute max")?;
         let distinct = self
-            .compute_distinct(array)
+            .compute_distinct(array, approx_distinct)
             .with_context(|| "Failed to compute distinct")?;
 
         let hist = self
@@ -349,10 +571,7 @@ impl Profiler {
                 min: f64::from(*min),
                 max: f64::from(*max),
                 timestamp: chrono::Utc::now().naive_utc(),
-                distinct: Distinct {
-                    count: dist.count,
-                    percent: dist.percent,
-                },
+                distinct: dist.clone(),
                 quantiles: Quantiles {
                     q25: f64::from(*q25),
                     q50: f64::from(*q50),
@@ -360,12 +579,16 @@ impl Profiler {
                     q99: f64::from(*q99),
                 },
                 histogram: hist[&features[i]].clone(),
+                boolean: None,
+                temporal: None,
+                missing_count: 0,
+                missing_rate: 0.0,
             };
 
             profiles.insert(features[i].clone(), profile);
         }
 
-        Ok(DataProfile { features: profiles })
+        Ok(profiles)
     }
 }
 
@@ -404,7 +627,7 @@ mod tests {
         let bin_size = 20;
 
         let profile = profiler
-            .compute_stats(&features, &array.view(), &bin_size)
+            .compute_stats(&features, &array.view(), &bin_size, false, &[], &[])
             .unwrap();
 
         assert_eq!(profile.features.len(), 3);
@@ -473,7 +696,7 @@ mod tests {
         let profiler = Profiler::default();
 
         let profile = profiler
-            .compute_stats(&features, &array.view(), &bin_size)
+            .compute_stats(&features, &array.view(), &bin_size, false, &[], &[])
             .unwrap();
 
         assert_eq!(profile.features.len(), 3);
@@ -523,4 +746,130 @@ mod tests {
         // convert to json
         let _ = profile.model_dump_json();
     }
+
+    #[test]
+    fn test_compute_distinct_approx() {
+        // 1000 rows with 100 distinct values per column
+        let array = Array::from_shape_fn((1000, 2), |(row, col)| ((row % 100) * (col + 1)) as f64);
+
+        let profiler = Profiler::default();
+
+        let exact = profiler.compute_distinct(&array.view(), false).unwrap();
+        let approx = profiler.compute_distinct(&array.view(), true).unwrap();
+
+        assert_eq!(exact[0].count, 100);
+        assert!((approx[0].count as i64 - 100).abs() <= 2);
+        assert!(relative_eq!(approx[1].percent, 0.1, epsilon = 0.01));
+
+        // every value occurs 10 times
+        assert_eq!(exact[0].top_values.len(), 10);
+        assert_eq!(exact[0].top_values[0].1, 10);
+        assert_eq!(approx[0].top_values.len(), 10);
+    }
+
+    #[test]
+    fn test_compute_boolean_and_temporal_stats() {
+        // 2024-01-01 00:30 UTC was a Monday, rows step forward by 25 hours
+        let start = 1_704_069_000.0;
+        let array = Array::from_shape_fn((48, 2), |(row, col)| match col {
+            0 => (row % 4 == 0) as u8 as f64,
+            _ => start + row as f64 * 25.0 * SECONDS_PER_HOUR,
+        });
+        let features = vec!["flag".to_string(), "event_time".to_string()];
+
+        let profiler = Profiler::default();
+        let profile = profiler
+            .compute_stats(
+                &features,
+                &array.view(),
+                &10,
+                false,
+                &features[..1],
+                &features[1..],
+            )
+            .unwrap();
+
+        let boolean = profile.features["flag"].boolean.as_ref().unwrap();
+        assert_eq!(boolean.true_count, 12);
+        assert_eq!(boolean.false_count, 36);
+        assert!(profile.features["flag"].temporal.is_none());
+
+        let temporal = profile.features["event_time"].temporal.as_ref().unwrap();
+        assert_eq!(temporal.weekday_counts.iter().sum::<usize>(), 48);
+        assert_eq!(temporal.weekday_counts[0], 7);
+        assert_eq!(temporal.hour_counts, vec![2; 24]);
+        assert!(profile.features["event_time"].boolean.is_none());
+    }
+
+    #[test]
+    fn test_compute_stats_missing_values() {
+        // every 6th flag and every 8th timestamp is missing
+        let array = Array::from_shape_fn((48, 3), |(row, col)| match col {
+            0 if row % 6 == 0 => f64::NAN,
+            0 => (row % 4 == 1) as u8 as f64,
+            1 if row % 8 == 0 => f64::NAN,
+            1 => row as f64 * SECONDS_PER_HOUR,
+            _ => row as f64,
+        });
+        let features = vec![
+            "flag".to_string(),
+            "event_time".to_string(),
+            "value".to_string(),
+        ];
+
+        let profiler = Profiler::default();
+        let profile = profiler
+            .compute_stats(
+                &features,
+                &array.view(),
+                &10,
+                false,
+                &features[..1],
+                &features[1..2],
+            )
+            .unwrap();
+
+        let flag = &profile.features["flag"];
+        assert_eq!(flag.missing_count, 8);
+        assert_eq!(flag.missing_rate, 8.0 / 48.0);
+        assert_eq!(
+            flag.boolean,
+            Some(BooleanStats {
+                true_count: 12,
+                false_count: 28,
+                null_count: 8,
+            })
+        );
+        // the mean is the rate of true values among non-missing values
+        assert_eq!(flag.mean, 12.0 / 40.0);
+        assert_eq!(flag.histogram.bin_counts.iter().sum::<i32>(), 40);
+
+        let event_time = &profile.features["event_time"];
+        assert_eq!(event_time.missing_count, 6);
+        let temporal = event_time.temporal.as_ref().unwrap();
+        assert_eq!(temporal.hour_counts.iter().sum::<usize>(), 42);
+        assert!(event_time.max.is_finite());
+
+        let value = &profile.features["value"];
+        assert_eq!(value.missing_count, 0);
+        assert_eq!(value.missing_rate, 0.0);
+        assert_eq!(value.mean, 23.5);
+
+        // only boolean and datetime features may contain missing values
+        assert!(profiler
+            .compute_stats(&features, &array.view(), &10, false, &[], &features[1..2])
+            .is_err());
+
+        let empty = Array::from_elem((4, 1), f64::NAN);
+        assert!(profiler
+            .compute_stats(
+                &features[..1],
+                &empty.view(),
+                &10,
+                false,
+                &features[..1],
+                &[]
+            )
+            .is_err());
+    }
 }
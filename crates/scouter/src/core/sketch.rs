@@ -0,0 +1,240 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::{Hash, Hasher};
+
+fn hash_value<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// HyperLogLog sketch for estimating the number of distinct values in a stream
+///
+/// # Arguments
+///
+/// * `precision` - Number of bits used to index registers (4..=16). Uses 2^precision registers
+///
+/// The standard error of the estimate is roughly 1.04 / sqrt(2^precision)
+pub struct HyperLogLog {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    pub fn new(precision: u8) -> Self {
+        let precision = precision.clamp(4, 16);
+        HyperLogLog {
+            precision,
+            registers: vec![0; 1 << precision],
+        }
+    }
+
+    pub fn insert<T: Hash + ?Sized>(&mut self, value: &T) {
+        let hash = hash_value(value);
+        let index = (hash >> (64 - self.precision)) as usize;
+
+        // remaining bits are used to find the position of the leftmost 1
+        let remaining = hash << self.precision;
+        let max_rank = 64 - self.precision + 1;
+        let rank = (remaining.leading_zeros() as u8 + 1).min(max_rank);
+
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|register| 2f64.powi(-(*register as i32)))
+            .sum();
+        let estimate = alpha * m * m / sum;
+
+        // small range correction (linear counting)
+        let zeros = self.registers.iter().filter(|r| **r == 0).count();
+        if estimate <= 2.5 * m && zeros > 0 {
+            return (m * (m / zeros as f64).ln()).round() as usize;
+        }
+
+        estimate.round() as usize
+    }
+}
+
+/// Space-saving sketch for tracking the most frequent values in a stream
+///
+/// # Arguments
+///
+/// * `capacity` - The number of counters to keep. Counts are exact when the number of
+///   distinct values is below capacity and overestimated by at most n / capacity otherwise
+///
+/// Counters are indexed by a min-heap, so an eviction costs O(log capacity) amortized.
+/// Heap entries are not updated when a counter is incremented. Stale entries are
+/// refreshed when they reach the top of the heap.
+pub struct SpaceSaving {
+    capacity: usize,
+    counters: HashMap<String, usize>,
+    heap: BinaryHeap<Reverse<(usize, String)>>,
+}
+
+impl SpaceSaving {
+    pub fn new(capacity: usize) -> Self {
+        SpaceSaving {
+            capacity: capacity.max(1),
+            counters: HashMap::new(),
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    pub fn insert(&mut self, value: String) {
+        if let Some(count) = self.counters.get_mut(&value) {
+            *count += 1;
+            return;
+        }
+
+        if self.counters.len() < self.capacity {
+            self.counters.insert(value.clone(), 1);
+            self.heap.push(Reverse((1, value)));
+            return;
+        }
+
+        // evict the smallest counter and inherit its count
+        let min_count = self.pop_min();
+        self.counters.insert(value.clone(), min_count + 1);
+        self.heap.push(Reverse((min_count + 1, value)));
+    }
+
+    // removes the counter with the smallest count and returns its count
+    fn pop_min(&mut self) -> usize {
+        while let Some(Reverse((count, value))) = self.heap.pop() {
+            let current = self.counters[&value];
+
+            if current == count {
+                self.counters.remove(&value);
+                return count;
+            }
+
+            // the counter was incremented since it was pushed
+            self.heap.push(Reverse((current, value)));
+        }
+
+        unreachable!("space-saving heap is empty while at capacity")
+    }
+
+    /// Returns the k most frequent values and their (estimated) counts
+    pub fn top_k(&self, k: usize) -> Vec<(String, usize)> {
+        top_k_counts(&self.counters, k)
+    }
+}
+
+/// Returns the k largest entries of a count map sorted by count, then value
+///
+/// Uses a bounded min-heap of size k, so only the returned values are cloned
+pub fn top_k_counts(counts: &HashMap<String, usize>, k: usize) -> Vec<(String, usize)> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    // ranks by count, then by the smallest value, the heap root is the worst kept entry
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for (value, count) in counts {
+        heap.push(Reverse((*count, Reverse(value))));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((count, Reverse(value)))| (value.clone(), count))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_hyperloglog() {
+        let mut hll = HyperLogLog::new(14);
+
+        for i in 0..100_000 {
+            hll.insert(&i.to_string());
+            // duplicates should not change the estimate
+            hll.insert(&i.to_string());
+        }
+
+        let count = hll.count() as f64;
+        assert!((count - 100_000.0).abs() / 100_000.0 < 0.03);
+
+        // small cardinalities are near exact
+        let mut hll = HyperLogLog::new(14);
+        for i in 0..100 {
+            hll.insert(&i);
+        }
+        assert!((hll.count() as i64 - 100).abs() <= 1);
+    }
+
+    #[test]
+    fn test_space_saving() {
+        let mut sketch = SpaceSaving::new(10);
+
+        for i in 0..1000 {
+            sketch.insert("heavy".to_string());
+            sketch.insert((i % 3).to_string());
+            sketch.insert(format!("noise_{}", i));
+        }
+
+        let top = sketch.top_k(2);
+        assert_eq!(top[0].0, "heavy");
+        assert!(top[0].1 >= 1000);
+        assert_eq!(top.len(), 2);
+
+        // counts are exact below capacity
+        let mut sketch = SpaceSaving::new(10);
+        for i in 0..5 {
+            for _ in 0..=i {
+                sketch.insert(i.to_string());
+            }
+        }
+        assert_eq!(
+            sketch.top_k(3),
+            vec![
+                ("4".to_string(), 5),
+                ("3".to_string(), 4),
+                ("2".to_string(), 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_top_k_counts() {
+        let counts = HashMap::from([
+            ("a".to_string(), 1),
+            ("b".to_string(), 3),
+            ("c".to_string(), 3),
+            ("d".to_string(), 2),
+        ]);
+
+        assert_eq!(
+            top_k_counts(&counts, 3),
+            vec![
+                ("b".to_string(), 3),
+                ("c".to_string(), 3),
+                ("d".to_string(), 2)
+            ]
+        );
+        assert_eq!(top_k_counts(&counts, 10).len(), 4);
+        assert!(top_k_counts(&counts, 0).is_empty());
+    }
+}
@@ -1,5 +1,7 @@
-use chrono::Utc;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use cron::Schedule;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
@@ -181,6 +183,78 @@ impl CommonCron {
     }
 }
 
+/// Validated cron expression used to preview upcoming run times
+///
+/// # Arguments
+///
+/// * `cron` - The cron expression (sec min hour day-of-month month day-of-week [year])
+///
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct CronSchedule {
+    #[pyo3(get)]
+    pub cron: String,
+
+    schedule: Schedule,
+}
+
+#[pymethods]
+impl CronSchedule {
+    #[new]
+    pub fn new(cron: String) -> PyResult<Self> {
+        Self::from_cron(&cron).map_err(|e| PyValueError::new_err(format!("{:?}", e)))
+    }
+
+    /// Next run time of the schedule, or None once the schedule has no upcoming runs
+    pub fn get_next(&self) -> Option<String> {
+        self.next_runs(1).first().map(|run| run.to_string())
+    }
+
+    /// Preview the next run times of the schedule
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of upcoming run times to return
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<String>` - Upcoming run times in UTC
+    #[pyo3(signature = (count=5))]
+    pub fn preview(&self, count: usize) -> Vec<String> {
+        self.next_runs(count)
+            .iter()
+            .map(|run| run.to_string())
+            .collect()
+    }
+
+    pub fn __str__(&self) -> String {
+        self.cron.clone()
+    }
+}
+
+impl CronSchedule {
+    pub fn from_cron(cron: &str) -> Result<Self> {
+        let schedule = Schedule::from_str(cron)
+            .with_context(|| format!("Invalid cron expression: {}", cron))?;
+
+        if schedule.upcoming(Utc).next().is_none() {
+            return Err(anyhow::anyhow!(
+                "Cron expression {} has no upcoming run times",
+                cron
+            ));
+        }
+
+        Ok(Self {
+            cron: cron.to_string(),
+            schedule,
+        })
+    }
+
+    pub fn next_runs(&self, count: usize) -> Vec<DateTime<Utc>> {
+        self.schedule.upcoming(Utc).take(count).collect()
+    }
+}
+
 // test crons
 
 #[cfg(test)]
@@ -248,4 +322,23 @@ mod tests {
         assert_eq!(cron.EVERY_DAY, "0 0 0 * * *");
         assert_eq!(cron.EVERY_WEEK, "0 0 0 * * SUN");
     }
+
+    #[test]
+    fn test_cron_schedule() {
+        let cron = CronSchedule::from_cron(&EveryHour::new().cron).unwrap();
+
+        let runs = cron.next_runs(3);
+        assert_eq!(runs.len(), 3);
+        assert!(runs
+            .windows(2)
+            .all(|w| w[1] - w[0] == chrono::Duration::hours(1)));
+        assert_eq!(cron.preview(3).len(), 3);
+        assert_eq!(cron.get_next(), Some(cron.preview(1)[0].clone()));
+
+        assert!(CronSchedule::from_cron("not a cron").is_err());
+        assert!(CronSchedule::from_cron("0 0 25 * * *").is_err());
+
+        // valid syntax without upcoming runs
+        assert!(CronSchedule::from_cron("0 0 0 1 1 * 2000").is_err());
+    }
 }
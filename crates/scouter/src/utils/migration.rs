@@ -0,0 +1,259 @@
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+
+// Profiles saved before schema versions were added do not have a schema_version field
+pub const LEGACY_SCHEMA_VERSION: u32 = 1;
+
+pub const DRIFT_PROFILE_SCHEMA_VERSION: u32 = 4;
+pub const DATA_PROFILE_SCHEMA_VERSION: u32 = 4;
+
+const SCHEMA_VERSION_KEY: &str = "schema_version";
+
+// A migration upgrades a serialized profile from version N to N + 1
+type Migration = fn(&mut Value) -> Result<()>;
+
+// Migrations are ordered by the version they upgrade from, starting at LEGACY_SCHEMA_VERSION
+const DRIFT_PROFILE_MIGRATIONS: &[Migration] = &[
+    drift_profile_v1_to_v2,
+    drift_profile_v2_to_v3,
+    drift_profile_v3_to_v4,
+];
+const DATA_PROFILE_MIGRATIONS: &[Migration] = &[
+    data_profile_v1_to_v2,
+    data_profile_v2_to_v3,
+    data_profile_v3_to_v4,
+];
+
+pub fn legacy_schema_version() -> u32 {
+    LEGACY_SCHEMA_VERSION
+}
+
+// v2 added feature aliases and feature weights
+fn drift_profile_v1_to_v2(profile: &mut Value) -> Result<()> {
+    let profile = profile
+        .as_object_mut()
+        .with_context(|| "Drift profile must be a JSON object")?;
+
+    profile
+        .entry("feature_aliases")
+        .or_insert_with(|| json!({}));
+    profile
+        .entry("feature_weights")
+        .or_insert_with(|| json!({}));
+
+    Ok(())
+}
+
+// v3 added computed feature expressions
+fn drift_profile_v2_to_v3(profile: &mut Value) -> Result<()> {
+    profile
+        .as_object_mut()
+        .with_context(|| "Drift profile must be a JSON object")?
+        .entry("computed_features")
+        .or_insert_with(|| json!({}));
+
+    Ok(())
+}
+
+// v4 added EWMA and CUSUM alert rules
+fn drift_profile_v3_to_v4(profile: &mut Value) -> Result<()> {
+    let alert_rule = profile
+        .pointer_mut("/config/alert_rule")
+        .and_then(Value::as_object_mut)
+        .with_context(|| "Drift profile must contain an alert rule")?;
+
+    alert_rule.entry("ewma").or_insert(Value::Null);
+    alert_rule.entry("cusum").or_insert(Value::Null);
+
+    Ok(())
+}
+
+// v2 added the most frequent values to the distinct stats of each feature
+fn data_profile_v1_to_v2(profile: &mut Value) -> Result<()> {
+    let features = profile
+        .get_mut("features")
+        .and_then(Value::as_object_mut)
+        .with_context(|| "Data profile must contain a features object")?;
+
+    for feature in features.values_mut() {
+        if let Some(distinct) = feature.get_mut("distinct").and_then(Value::as_object_mut) {
+            distinct.entry("top_values").or_insert_with(|| json!([]));
+        }
+    }
+
+    Ok(())
+}
+
+// v3 added boolean and datetime stats to each feature
+fn data_profile_v2_to_v3(profile: &mut Value) -> Result<()> {
+    let features = profile
+        .get_mut("features")
+        .and_then(Value::as_object_mut)
+        .with_context(|| "Data profile must contain a features object")?;
+
+    for feature in features.values_mut() {
+        if let Some(feature) = feature.as_object_mut() {
+            feature.entry("boolean").or_insert(Value::Null);
+            feature.entry("temporal").or_insert(Value::Null);
+        }
+    }
+
+    Ok(())
+}
+
+// v4 added missing value counts to each feature and null counts to boolean stats.
+// Older profiles could not be created from data with missing values
+fn data_profile_v3_to_v4(profile: &mut Value) -> Result<()> {
+    let features = profile
+        .get_mut("features")
+        .and_then(Value::as_object_mut)
+        .with_context(|| "Data profile must contain a features object")?;
+
+    for feature in features.values_mut() {
+        if let Some(feature) = feature.as_object_mut() {
+            feature.entry("missing_count").or_insert_with(|| json!(0));
+            feature.entry("missing_rate").or_insert_with(|| json!(0.0));
+
+            if let Some(boolean) = feature.get_mut("boolean").and_then(Value::as_object_mut) {
+                boolean.entry("null_count").or_insert_with(|| json!(0));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn migrate(mut profile: Value, migrations: &[Migration], current_version: u32) -> Result<Value> {
+    let version = match profile.get(SCHEMA_VERSION_KEY) {
+        Some(version) => version
+            .as_u64()
+            .with_context(|| "Schema version must be a positive integer")?
+            as u32,
+        None => LEGACY_SCHEMA_VERSION,
+    };
+
+    if version > current_version {
+        return Err(anyhow::anyhow!(
+            "Profile schema version {} is newer than the supported version {}. Upgrade scouter to load it",
+            version,
+            current_version
+        ));
+    }
+
+    for migration in migrations
+        .iter()
+        .skip(version.saturating_sub(LEGACY_SCHEMA_VERSION) as usize)
+    {
+        migration(&mut profile)?;
+    }
+
+    profile
+        .as_object_mut()
+        .with_context(|| "Profile must be a JSON object")?
+        .insert(SCHEMA_VERSION_KEY.to_string(), json!(current_version));
+
+    Ok(profile)
+}
+
+/// Upgrade a serialized drift profile of any supported schema version to the current version
+pub fn migrate_drift_profile(profile: Value) -> Result<Value> {
+    migrate(
+        profile,
+        DRIFT_PROFILE_MIGRATIONS,
+        DRIFT_PROFILE_SCHEMA_VERSION,
+    )
+}
+
+/// Upgrade a serialized data profile of any supported schema version to the current version
+pub fn migrate_data_profile(profile: Value) -> Result<Value> {
+    migrate(
+        profile,
+        DATA_PROFILE_MIGRATIONS,
+        DATA_PROFILE_SCHEMA_VERSION,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_migrations_cover_all_versions() {
+        assert_eq!(
+            DRIFT_PROFILE_MIGRATIONS.len() as u32,
+            DRIFT_PROFILE_SCHEMA_VERSION - LEGACY_SCHEMA_VERSION
+        );
+        assert_eq!(
+            DATA_PROFILE_MIGRATIONS.len() as u32,
+            DATA_PROFILE_SCHEMA_VERSION - LEGACY_SCHEMA_VERSION
+        );
+    }
+
+    #[test]
+    fn test_migrate_drift_profile() {
+        let legacy: Value =
+            serde_json::from_str(include_str!("../../tests/corpus/drift_profile_v0_2_0.json"))
+                .unwrap();
+
+        let migrated = migrate_drift_profile(legacy).unwrap();
+        assert_eq!(migrated[SCHEMA_VERSION_KEY], DRIFT_PROFILE_SCHEMA_VERSION);
+        assert_eq!(migrated["feature_aliases"], json!({}));
+        assert_eq!(migrated["feature_weights"], json!({}));
+        assert_eq!(migrated["computed_features"], json!({}));
+        assert_eq!(migrated["config"]["alert_rule"]["ewma"], Value::Null);
+        assert_eq!(migrated["config"]["alert_rule"]["cusum"], Value::Null);
+
+        // v2 profiles only need the v3 and v4 migrations
+        let mut v2 = migrated.clone();
+        v2[SCHEMA_VERSION_KEY] = json!(2);
+        v2.as_object_mut().unwrap().remove("computed_features");
+        for rule in ["ewma", "cusum"] {
+            v2["config"]["alert_rule"]
+                .as_object_mut()
+                .unwrap()
+                .remove(rule);
+        }
+        assert_eq!(migrate_drift_profile(v2).unwrap(), migrated);
+
+        // migrating a current profile is a no-op
+        assert_eq!(migrate_drift_profile(migrated.clone()).unwrap(), migrated);
+
+        let mut future = migrated;
+        future[SCHEMA_VERSION_KEY] = json!(DRIFT_PROFILE_SCHEMA_VERSION + 1);
+        assert!(migrate_drift_profile(future).is_err());
+    }
+
+    #[test]
+    fn test_migrate_data_profile() {
+        let legacy: Value =
+            serde_json::from_str(include_str!("../../tests/corpus/data_profile_v0_2_0.json"))
+                .unwrap();
+
+        let migrated = migrate_data_profile(legacy).unwrap();
+        assert_eq!(migrated[SCHEMA_VERSION_KEY], DATA_PROFILE_SCHEMA_VERSION);
+        assert_eq!(
+            migrated["features"]["feature_0"]["distinct"]["top_values"],
+            json!([])
+        );
+        assert_eq!(migrated["features"]["feature_0"]["boolean"], Value::Null);
+        assert_eq!(migrated["features"]["feature_0"]["temporal"], Value::Null);
+        assert_eq!(migrated["features"]["feature_0"]["missing_count"], json!(0));
+        assert_eq!(
+            migrated["features"]["feature_0"]["missing_rate"],
+            json!(0.0)
+        );
+
+        // v3 boolean stats get a null count
+        let mut v3 = migrated.clone();
+        v3[SCHEMA_VERSION_KEY] = json!(3);
+        v3["features"]["feature_0"]["boolean"] = json!({"true_count": 1, "false_count": 2});
+        let v4 = migrate_data_profile(v3).unwrap();
+        assert_eq!(
+            v4["features"]["feature_0"]["boolean"],
+            json!({"true_count": 1, "false_count": 2, "null_count": 0})
+        );
+
+        assert!(migrate_data_profile(json!({"schema_version": "2"})).is_err());
+    }
+}
@@ -1,2 +1,3 @@
 pub mod cron;
+pub mod migration;
 pub mod types;
@@ -1,15 +1,17 @@
-use crate::utils::cron::EveryDay;
-use anyhow::Context;
+use crate::utils::cron::{CronSchedule, EveryDay};
+use crate::utils::migration::{legacy_schema_version, migrate_data_profile, migrate_drift_profile};
+use anyhow::{Context, Result};
 use ndarray::Array;
 use ndarray::Array2;
 use numpy::{IntoPyArray, PyArray2};
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::path::PathBuf;
-use std::str::FromStr;
 
 enum FileName {
     Drift,
@@ -60,6 +62,105 @@ impl PercentageAlertRule {
     }
 }
 
+/// Exponentially weighted moving average (EWMA) chart rule
+///
+/// # Arguments
+///
+/// * `lambda` - The weight given to the most recent sample, between 0 and 1
+/// * `limit` - The width of the control limits in standard deviations of the EWMA statistic
+///
+/// The EWMA statistic is not persisted between drift computations. Each call starts from the
+/// profile center, so compute drift over a window of consecutive samples.
+///
+#[pyclass]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct EwmaAlertRule {
+    #[pyo3(get, name = "lambda_")]
+    pub lambda: f64,
+
+    #[pyo3(get)]
+    pub limit: f64,
+}
+
+#[pymethods]
+impl EwmaAlertRule {
+    // lambda is a reserved keyword in python
+    #[new]
+    #[pyo3(signature = (lambda_=None, limit=None))]
+    pub fn new(lambda_: Option<f64>, limit: Option<f64>) -> PyResult<Self> {
+        Self::try_new(lambda_, limit).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+}
+
+impl EwmaAlertRule {
+    pub fn try_new(lambda: Option<f64>, limit: Option<f64>) -> Result<Self> {
+        let lambda = lambda.unwrap_or(0.2);
+        let limit = limit.unwrap_or(3.0);
+
+        if !(lambda > 0.0 && lambda <= 1.0) {
+            return Err(anyhow::anyhow!(
+                "EWMA lambda must be between 0 (exclusive) and 1, got {}",
+                lambda
+            ));
+        }
+
+        if !(limit > 0.0 && limit.is_finite()) {
+            return Err(anyhow::anyhow!(
+                "EWMA limit must be positive, got {}",
+                limit
+            ));
+        }
+
+        Ok(Self { lambda, limit })
+    }
+}
+
+/// Cumulative sum (CUSUM) chart rule
+///
+/// # Arguments
+///
+/// * `k` - The allowance (slack) in sigma units of the profile, typically half the shift to detect
+/// * `h` - The decision interval in sigma units of the profile
+///
+/// The cumulative sums are not persisted between drift computations. Each call starts from
+/// zero, so compute drift over a window of consecutive samples.
+///
+#[pyclass]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct CusumAlertRule {
+    #[pyo3(get)]
+    pub k: f64,
+
+    #[pyo3(get)]
+    pub h: f64,
+}
+
+#[pymethods]
+impl CusumAlertRule {
+    #[new]
+    #[pyo3(signature = (k=None, h=None))]
+    pub fn new(k: Option<f64>, h: Option<f64>) -> PyResult<Self> {
+        Self::try_new(k, h).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+}
+
+impl CusumAlertRule {
+    pub fn try_new(k: Option<f64>, h: Option<f64>) -> Result<Self> {
+        let k = k.unwrap_or(0.5);
+        let h = h.unwrap_or(5.0);
+
+        if !(k >= 0.0 && k.is_finite()) {
+            return Err(anyhow::anyhow!("CUSUM k must be non-negative, got {}", k));
+        }
+
+        if !(h > 0.0 && h.is_finite()) {
+            return Err(anyhow::anyhow!("CUSUM h must be positive, got {}", h));
+        }
+
+        Ok(Self { k, h })
+    }
+}
+
 #[pyclass]
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 pub struct AlertRule {
@@ -68,39 +169,70 @@ pub struct AlertRule {
 
     #[pyo3(get, set)]
     pub percentage: Option<PercentageAlertRule>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub ewma: Option<EwmaAlertRule>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub cusum: Option<CusumAlertRule>,
 }
 
 // impl new method
 #[pymethods]
 impl AlertRule {
     #[new]
+    #[pyo3(signature = (percentage_rule=None, process_rule=None, ewma_rule=None, cusum_rule=None))]
     pub fn new(
         percentage_rule: Option<PercentageAlertRule>,
         process_rule: Option<ProcessAlertRule>,
+        ewma_rule: Option<EwmaAlertRule>,
+        cusum_rule: Option<CusumAlertRule>,
     ) -> Self {
-        // if both are None, return default control rule
-        if percentage_rule.is_none() && process_rule.is_none() {
+        // if all are None, return default control rule
+        if percentage_rule.is_none()
+            && process_rule.is_none()
+            && ewma_rule.is_none()
+            && cusum_rule.is_none()
+        {
             return Self {
                 process: Some(ProcessAlertRule::new(None)),
                 percentage: None,
+                ewma: None,
+                cusum: None,
             };
         }
 
         Self {
             process: process_rule,
             percentage: percentage_rule,
+            ewma: ewma_rule,
+            cusum: cusum_rule,
         }
     }
 
     pub fn to_str(&self) -> String {
-        if self.process.is_some() {
-            return self.process.as_ref().unwrap().rule.clone();
+        if let Some(process) = &self.process {
+            process.rule.clone()
+        } else if let Some(ewma) = &self.ewma {
+            format!("ewma lambda={} limit={}", ewma.lambda, ewma.limit)
+        } else if let Some(cusum) = &self.cusum {
+            format!("cusum k={} h={}", cusum.k, cusum.h)
         } else {
-            return self.percentage.as_ref().unwrap().rule.to_string();
+            self.percentage.as_ref().unwrap().rule.to_string()
         }
     }
 }
 
+impl AlertRule {
+    // EWMA and CUSUM rules are evaluated on the sequence of samples instead of sample by sample.
+    // Process rules take precedence when several rules are set
+    pub fn is_chart_rule(&self) -> bool {
+        self.process.is_none() && (self.ewma.is_some() || self.cusum.is_some())
+    }
+}
+
 #[pyclass]
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone, std::cmp::Eq, Hash)]
 pub enum AlertZone {
@@ -133,6 +265,8 @@ pub enum AlertType {
     AllGood,
     Trend,
     Percentage,
+    Ewma,
+    Cusum,
 }
 
 #[pymethods]
@@ -145,6 +279,8 @@ impl AlertType {
             AlertType::AllGood => "All good".to_string(),
             AlertType::Trend => "Trend".to_string(),
             AlertType::Percentage => "Percentage".to_string(),
+            AlertType::Ewma => "EWMA".to_string(),
+            AlertType::Cusum => "CUSUM".to_string(),
         }
     }
 }
@@ -305,30 +441,73 @@ impl DriftConfig {
         sample_size: Option<usize>,
         schedule: Option<String>,
         alert_rule: Option<AlertRule>,
+    ) -> Self {
+        // invalid schedules fall back to the default schedule
+        let schedule = schedule.filter(|schedule| match CronSchedule::from_cron(schedule) {
+            Ok(_) => true,
+            Err(e) => {
+                tracing::warn!("{:?}. Using default schedule", e);
+                false
+            }
+        });
+
+        Self::with_schedule(
+            name,
+            repository,
+            version,
+            sample,
+            sample_size,
+            schedule,
+            alert_rule,
+        )
+    }
+}
+
+impl DriftConfig {
+    /// Creates a drift config, returning an error instead of falling back to the default
+    /// schedule when the cron schedule is invalid or has no upcoming run times
+    pub fn try_new(
+        name: String,
+        repository: String,
+        version: Option<String>,
+        sample: Option<bool>,
+        sample_size: Option<usize>,
+        schedule: Option<String>,
+        alert_rule: Option<AlertRule>,
+    ) -> Result<Self> {
+        let schedule = match schedule {
+            Some(schedule) => Some(CronSchedule::from_cron(&schedule)?.cron),
+            None => None,
+        };
+
+        Ok(Self::with_schedule(
+            name,
+            repository,
+            version,
+            sample,
+            sample_size,
+            schedule,
+            alert_rule,
+        ))
+    }
+
+    fn with_schedule(
+        name: String,
+        repository: String,
+        version: Option<String>,
+        sample: Option<bool>,
+        sample_size: Option<usize>,
+        schedule: Option<String>,
+        alert_rule: Option<AlertRule>,
     ) -> Self {
         let sample = sample.unwrap_or(true);
         let sample_size = sample_size.unwrap_or(25);
 
         let version = version.unwrap_or("0.1.0".to_string());
 
-        let alert_rule = alert_rule.unwrap_or(AlertRule::new(None, None));
-
-        let schedule = match schedule {
-            Some(s) => {
-                // validate the cron schedule
-                let schedule = cron::Schedule::from_str(&s);
-
-                match schedule {
-                    Ok(_) => s,
-                    Err(_) => {
-                        tracing::error!("Invalid cron schedule, using default schedule");
-                        EveryDay::new().cron
-                    }
-                }
-            }
+        let alert_rule = alert_rule.unwrap_or(AlertRule::new(None, None, None, None));
 
-            None => EveryDay::new().cron,
-        };
+        let schedule = schedule.unwrap_or(EveryDay::new().cron);
 
         Self {
             sample_size,
@@ -342,6 +521,20 @@ impl DriftConfig {
     }
 }
 
+/// Python class for a drift profile
+///
+/// # Arguments
+///
+/// * `features` - A hashmap of feature names and their drift profiles
+/// * `config` - The drift config
+/// * `feature_aliases` - A hashmap of alternative feature names (e.g. after an upstream rename)
+///   and the profile feature they map to
+/// * `feature_weights` - Optional feature weights (e.g. feature importance) used when aggregating
+///   feature drift into a model-level drift score
+/// * `computed_features` - A hashmap of computed feature names and the expressions used to
+///   compute them from other features
+/// * `schema_version` - The schema version the profile was saved with
+///
 #[pyclass]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct DriftProfile {
@@ -350,6 +543,22 @@ pub struct DriftProfile {
 
     #[pyo3(get, set)]
     pub config: DriftConfig,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub feature_aliases: HashMap<String, String>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub feature_weights: HashMap<String, f64>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub computed_features: HashMap<String, String>,
+
+    #[pyo3(get)]
+    #[serde(default = "legacy_schema_version")]
+    pub schema_version: u32,
 }
 
 #[pymethods]
@@ -366,14 +575,65 @@ impl DriftProfile {
 
     #[staticmethod]
     pub fn load_from_json(model: String) -> DriftProfile {
-        // deserialize the string to a struct
-        serde_json::from_str(&model).expect("Failed to load monitor profile")
+        // deserialize the string to a struct, upgrading profiles saved with older schema versions
+        let value = serde_json::from_str(&model).expect("Failed to load monitor profile");
+        let value = migrate_drift_profile(value).expect("Failed to migrate monitor profile");
+        serde_json::from_value(value).expect("Failed to load monitor profile")
     }
 
     pub fn save_to_json(&self, path: Option<PathBuf>) -> PyResult<()> {
         ProfileFuncs::save_to_json(self, path, FileName::Profile.to_str())
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))
     }
+
+    /// Map an alternative feature name to a feature in the profile so that records and drift
+    /// for the alias are reported under the profile feature
+    ///
+    /// # Arguments
+    ///
+    /// * `alias` - The alternative feature name (e.g. the new name after an upstream rename)
+    /// * `feature` - The feature name in the profile
+    ///
+    pub fn add_feature_alias(&mut self, alias: String, feature: String) -> PyResult<()> {
+        self.try_add_feature_alias(alias, feature)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+}
+
+impl DriftProfile {
+    /// Map an alias to a profile feature. Aliases always point at a profile feature and
+    /// can not shadow one, so aliases never chain
+    pub fn try_add_feature_alias(&mut self, alias: String, feature: String) -> Result<()> {
+        if !self.features.contains_key(&feature) {
+            return Err(anyhow::anyhow!(
+                "Feature {} does not exist in the drift profile",
+                feature
+            ));
+        }
+
+        if self.features.contains_key(&alias) {
+            return Err(anyhow::anyhow!(
+                "Alias {} is already a feature in the drift profile",
+                alias
+            ));
+        }
+
+        self.feature_aliases.insert(alias, feature);
+        Ok(())
+    }
+
+    /// Resolve feature names to their profile feature names using the feature aliases
+    pub fn resolve_features(&self, features: &[String]) -> Vec<String> {
+        features
+            .iter()
+            .map(|feature| {
+                self.feature_aliases
+                    .get(feature)
+                    .unwrap_or(feature)
+                    .to_string()
+            })
+            .collect()
+    }
 }
 
 #[pyclass]
@@ -384,6 +644,63 @@ pub struct Distinct {
 
     #[pyo3(get, set)]
     pub percent: f64,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub top_values: Vec<(String, usize)>,
+}
+
+/// Counts of true, false and null values of a boolean feature
+///
+/// # Arguments
+///
+/// * `true_count` - The number of true values
+/// * `false_count` - The number of false values
+/// * `null_count` - The number of null values
+///
+#[pyclass]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BooleanStats {
+    #[pyo3(get)]
+    pub true_count: usize,
+
+    #[pyo3(get)]
+    pub false_count: usize,
+
+    #[pyo3(get)]
+    #[serde(default)]
+    pub null_count: usize,
+}
+
+#[pymethods]
+impl BooleanStats {
+    pub fn __str__(&self) -> String {
+        ProfileFuncs::__str__(self)
+    }
+}
+
+/// Distribution of a datetime feature over the days of the week and hours of the day (UTC)
+///
+/// # Arguments
+///
+/// * `weekday_counts` - The number of values per day of the week, starting on Monday
+/// * `hour_counts` - The number of values per hour of the day
+///
+#[pyclass]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TemporalStats {
+    #[pyo3(get)]
+    pub weekday_counts: Vec<usize>,
+
+    #[pyo3(get)]
+    pub hour_counts: Vec<usize>,
+}
+
+#[pymethods]
+impl TemporalStats {
+    pub fn __str__(&self) -> String {
+        ProfileFuncs::__str__(self)
+    }
 }
 
 #[pyclass]
@@ -415,6 +732,22 @@ pub struct FeatureDataProfile {
 
     #[pyo3(get, set)]
     pub histogram: Histogram,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub boolean: Option<BooleanStats>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub temporal: Option<TemporalStats>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub missing_count: usize,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub missing_rate: f64,
 }
 
 #[pyclass]
@@ -422,6 +755,10 @@ pub struct FeatureDataProfile {
 pub struct DataProfile {
     #[pyo3(get, set)]
     pub features: HashMap<String, FeatureDataProfile>,
+
+    #[pyo3(get)]
+    #[serde(default = "legacy_schema_version")]
+    pub schema_version: u32,
 }
 
 #[pymethods]
@@ -438,14 +775,173 @@ impl DataProfile {
 
     #[staticmethod]
     pub fn load_from_json(model: String) -> DataProfile {
-        // deserialize the string to a struct
-        serde_json::from_str(&model).expect("Failed to load data profile")
+        // deserialize the string to a struct, upgrading profiles saved with older schema versions
+        let value = serde_json::from_str(&model).expect("Failed to load data profile");
+        let value = migrate_data_profile(value).expect("Failed to migrate data profile");
+        serde_json::from_value(value).expect("Failed to load data profile")
     }
 
     pub fn save_to_json(&self, path: Option<PathBuf>) -> PyResult<()> {
         ProfileFuncs::save_to_json(self, path, FileName::Profile.to_str())
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))
     }
+
+    /// Compare this (baseline) profile to another profile
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The profile to compare against (e.g. serving data)
+    /// * `mean_threshold` - Max mean shift in baseline standard deviations. Default 0.25
+    /// * `quantile_threshold` - Max quantile shift in baseline standard deviations. Default 0.5
+    /// * `distinct_threshold` - Max relative change in distinct count. Default 0.1
+    ///
+    pub fn compare(
+        &self,
+        other: &DataProfile,
+        mean_threshold: Option<f64>,
+        quantile_threshold: Option<f64>,
+        distinct_threshold: Option<f64>,
+    ) -> DataProfileComparison {
+        let mean_threshold = mean_threshold.unwrap_or(0.25);
+        let quantile_threshold = quantile_threshold.unwrap_or(0.5);
+        let distinct_threshold = distinct_threshold.unwrap_or(0.1);
+
+        let mut comparison = DataProfileComparison::default();
+
+        for (feature, baseline) in self.features.iter() {
+            let Some(current) = other.features.get(feature) else {
+                comparison.missing_features.push(feature.clone());
+                continue;
+            };
+
+            // shifts are expressed in baseline standard deviations
+            let scale = if baseline.stddev > 0.0 {
+                baseline.stddev
+            } else {
+                1.0
+            };
+
+            let mean_shift = (current.mean - baseline.mean) / scale;
+            let quantile_shift = [
+                current.quantiles.q25 - baseline.quantiles.q25,
+                current.quantiles.q50 - baseline.quantiles.q50,
+                current.quantiles.q75 - baseline.quantiles.q75,
+                current.quantiles.q99 - baseline.quantiles.q99,
+            ]
+            .iter()
+            .map(|shift| (shift / scale).abs())
+            .fold(0.0, f64::max);
+            let distinct_change = (current.distinct.count as f64 - baseline.distinct.count as f64)
+                / (baseline.distinct.count.max(1) as f64);
+
+            let changed = mean_shift.abs() > mean_threshold
+                || quantile_shift > quantile_threshold
+                || distinct_change.abs() > distinct_threshold;
+
+            comparison.features.insert(
+                feature.clone(),
+                FeatureProfileChange {
+                    feature: feature.clone(),
+                    mean_shift,
+                    stddev_ratio: current.stddev / scale,
+                    quantile_shift,
+                    distinct_change,
+                    changed,
+                },
+            );
+        }
+
+        comparison.new_features = other
+            .features
+            .keys()
+            .filter(|feature| !self.features.contains_key(*feature))
+            .cloned()
+            .collect();
+
+        comparison.missing_features.sort();
+        comparison.new_features.sort();
+
+        comparison
+    }
+}
+
+/// Python class for the per-feature result of a data profile comparison
+///
+/// # Arguments
+///
+/// * `feature` - The feature name
+/// * `mean_shift` - Change in mean, in baseline standard deviations
+/// * `stddev_ratio` - Ratio of the compared stddev to the baseline stddev
+/// * `quantile_shift` - Largest absolute quantile change, in baseline standard deviations
+/// * `distinct_change` - Relative change in distinct count
+/// * `changed` - Whether any of the shifts exceeded its threshold
+///
+#[pyclass]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FeatureProfileChange {
+    #[pyo3(get, set)]
+    pub feature: String,
+
+    #[pyo3(get, set)]
+    pub mean_shift: f64,
+
+    #[pyo3(get, set)]
+    pub stddev_ratio: f64,
+
+    #[pyo3(get, set)]
+    pub quantile_shift: f64,
+
+    #[pyo3(get, set)]
+    pub distinct_change: f64,
+
+    #[pyo3(get, set)]
+    pub changed: bool,
+}
+
+/// Python class for the result of comparing two data profiles
+///
+/// # Arguments
+///
+/// * `features` - Per-feature changes for features present in both profiles
+/// * `new_features` - Features only present in the compared profile
+/// * `missing_features` - Features only present in the baseline profile
+///
+#[pyclass]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct DataProfileComparison {
+    #[pyo3(get, set)]
+    pub features: HashMap<String, FeatureProfileChange>,
+
+    #[pyo3(get, set)]
+    pub new_features: Vec<String>,
+
+    #[pyo3(get, set)]
+    pub missing_features: Vec<String>,
+}
+
+#[pymethods]
+impl DataProfileComparison {
+    pub fn __str__(&self) -> String {
+        // serialize the struct to a string
+        ProfileFuncs::__str__(self)
+    }
+
+    pub fn model_dump_json(&self) -> String {
+        // serialize the struct to a string
+        self.__str__()
+    }
+
+    /// Names of features whose shifts exceeded a threshold
+    pub fn changed_features(&self) -> Vec<String> {
+        let mut changed = self
+            .features
+            .values()
+            .filter(|change| change.changed)
+            .map(|change| change.feature.clone())
+            .collect::<Vec<_>>();
+        changed.sort();
+        changed
+    }
 }
 
 /// Python class for quantiles
@@ -646,6 +1142,16 @@ impl DriftMap {
         let (array, features) = self.to_array().unwrap();
         Ok((array.into_pyarray_bound(py).to_owned(), features))
     }
+
+    /// Convert the drift map to a long-format pandas dataframe with columns feature, sample and drift
+    pub fn to_pandas<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        columns_to_dataframe(py, "pandas", self.to_columns(py)?)
+    }
+
+    /// Convert the drift map to a long-format polars dataframe with columns feature, sample and drift
+    pub fn to_polars<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        columns_to_dataframe(py, "polars", self.to_columns(py)?)
+    }
 }
 
 impl DriftMap {
@@ -667,7 +1173,77 @@ impl DriftMap {
 
         Ok((array, features))
     }
+
+    /// Flatten the drift map into long-format (feature, sample, drift) columns
+    pub fn to_records(&self) -> (Vec<String>, Vec<f64>, Vec<f64>) {
+        let mut features = Vec::new();
+        let mut samples = Vec::new();
+        let mut drift = Vec::new();
+
+        for (feature, feature_drift) in self.features.iter() {
+            features.extend(std::iter::repeat_n(
+                feature.clone(),
+                feature_drift.samples.len(),
+            ));
+            samples.extend(feature_drift.samples.iter());
+            drift.extend(feature_drift.drift.iter());
+        }
+
+        (features, samples, drift)
+    }
+
+    fn to_columns<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let (features, samples, drift) = self.to_records();
+        let columns = PyDict::new_bound(py);
+        columns.set_item("feature", features)?;
+        columns.set_item("sample", samples)?;
+        columns.set_item("drift", drift)?;
+        Ok(columns)
+    }
 }
+
+/// Create a dataframe from a dictionary of columns using the given python module (pandas or polars)
+fn columns_to_dataframe<'py>(
+    py: Python<'py>,
+    module: &str,
+    columns: Bound<'py, PyDict>,
+) -> PyResult<Bound<'py, PyAny>> {
+    PyModule::import_bound(py, module)?
+        .getattr("DataFrame")?
+        .call1((columns,))
+}
+
+/// Python class for a model-level drift score
+///
+/// # Arguments
+///
+/// * `score` - Weighted drift score between 0 (no drift) and 1 (all features out of bounds),
+///   averaged over all samples
+/// * `trend` - Weighted drift score for each sample, in sample order
+///
+#[pyclass]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DriftScore {
+    #[pyo3(get, set)]
+    pub score: f64,
+
+    #[pyo3(get, set)]
+    pub trend: Vec<f64>,
+}
+
+#[pymethods]
+impl DriftScore {
+    pub fn __str__(&self) -> String {
+        // serialize the struct to a string
+        ProfileFuncs::__str__(self)
+    }
+
+    pub fn model_dump_json(&self) -> String {
+        // serialize the struct to a string
+        self.__str__()
+    }
+}
+
 // Drift config to use when calculating drift on a new sample of data
 
 #[pyclass]
@@ -734,6 +1310,35 @@ impl FeatureAlerts {
 
         self.features.insert(feature.to_string(), feature_alert);
     }
+
+    /// Flatten the feature alerts into long-format (feature, kind, zone) columns sorted by feature
+    pub fn to_records(&self) -> (Vec<String>, Vec<String>, Vec<String>) {
+        let mut features = Vec::new();
+        let mut kinds = Vec::new();
+        let mut zones = Vec::new();
+
+        let mut feature_alerts = self.features.values().collect::<Vec<_>>();
+        feature_alerts.sort_by(|a, b| a.feature.cmp(&b.feature));
+
+        for feature_alert in feature_alerts {
+            for alert in feature_alert.alerts.iter() {
+                features.push(feature_alert.feature.clone());
+                kinds.push(alert.kind.clone());
+                zones.push(alert.zone.clone());
+            }
+        }
+
+        (features, kinds, zones)
+    }
+
+    fn to_columns<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let (features, kinds, zones) = self.to_records();
+        let columns = PyDict::new_bound(py);
+        columns.set_item("feature", features)?;
+        columns.set_item("kind", kinds)?;
+        columns.set_item("zone", zones)?;
+        Ok(columns)
+    }
 }
 
 #[pymethods]
@@ -755,17 +1360,28 @@ impl FeatureAlerts {
         // serialize the struct to a string
         self.__str__()
     }
+
+    /// Convert the alerts to a long-format pandas dataframe with columns feature, kind and zone
+    pub fn to_pandas<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        columns_to_dataframe(py, "pandas", self.to_columns(py)?)
+    }
+
+    /// Convert the alerts to a long-format polars dataframe with columns feature, kind and zone
+    pub fn to_polars<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        columns_to_dataframe(py, "polars", self.to_columns(py)?)
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use crate::utils::migration::{DATA_PROFILE_SCHEMA_VERSION, DRIFT_PROFILE_SCHEMA_VERSION};
 
     #[test]
     fn test_types() {
         // write tests for all alerts
-        let control_alert = AlertRule::new(None, Some(ProcessAlertRule::new(None)));
+        let control_alert = AlertRule::new(None, Some(ProcessAlertRule::new(None)), None, None);
 
         assert_eq!(control_alert.to_str(), "8 16 4 8 2 4 1 1");
         assert_eq!(AlertZone::NotApplicable.to_str(), "NA");
@@ -778,8 +1394,476 @@ mod tests {
         assert_eq!(AlertType::Alternating.to_str(), "Alternating");
         assert_eq!(AlertType::OutOfBounds.to_str(), "Out of bounds");
         assert_eq!(AlertType::Percentage.to_str(), "Percentage");
+        assert_eq!(AlertType::Ewma.to_str(), "EWMA");
+        assert_eq!(AlertType::Cusum.to_str(), "CUSUM");
 
         let rule = PercentageAlertRule::new(None);
         assert_eq!(rule.rule, 0.1);
+
+        let config = |schedule: &str| {
+            DriftConfig::try_new(
+                "name".to_string(),
+                "repo".to_string(),
+                None,
+                None,
+                None,
+                Some(schedule.to_string()),
+                None,
+            )
+        };
+        assert_eq!(config("0 0 * * * *").unwrap().schedule, "0 0 * * * *");
+        assert!(config("not a cron").is_err());
+
+        // the python constructor keeps falling back to the default schedule
+        let config = DriftConfig::new(
+            "name".to_string(),
+            "repo".to_string(),
+            None,
+            None,
+            None,
+            Some("not a cron".to_string()),
+            None,
+        );
+        assert_eq!(config.schedule, EveryDay::new().cron);
+    }
+
+    #[test]
+    fn test_chart_rules() {
+        let ewma = EwmaAlertRule::try_new(None, None).unwrap();
+        assert_eq!((ewma.lambda, ewma.limit), (0.2, 3.0));
+        assert!(EwmaAlertRule::try_new(Some(0.0), None).is_err());
+        assert!(EwmaAlertRule::try_new(Some(1.5), None).is_err());
+        assert!(EwmaAlertRule::try_new(None, Some(-1.0)).is_err());
+
+        let cusum = CusumAlertRule::try_new(None, None).unwrap();
+        assert_eq!((cusum.k, cusum.h), (0.5, 5.0));
+        assert!(CusumAlertRule::try_new(Some(-0.5), None).is_err());
+        assert!(CusumAlertRule::try_new(None, Some(0.0)).is_err());
+
+        let rule = AlertRule::new(None, None, Some(ewma), None);
+        assert!(rule.is_chart_rule());
+        assert!(rule.process.is_none());
+        assert_eq!(rule.to_str(), "ewma lambda=0.2 limit=3");
+
+        let rule = AlertRule::new(None, None, None, Some(cusum));
+        assert!(rule.is_chart_rule());
+        assert!(!AlertRule::new(None, None, None, None).is_chart_rule());
+    }
+
+    fn feature_data_profile(id: &str, mean: f64, distinct: usize) -> FeatureDataProfile {
+        FeatureDataProfile {
+            id: id.to_string(),
+            mean,
+            stddev: 1.0,
+            min: mean - 3.0,
+            max: mean + 3.0,
+            timestamp: chrono::Utc::now().naive_utc(),
+            distinct: Distinct {
+                count: distinct,
+                percent: distinct as f64 / 1000.0,
+                top_values: Vec::new(),
+            },
+            quantiles: Quantiles {
+                q25: mean - 0.67,
+                q50: mean,
+                q75: mean + 0.67,
+                q99: mean + 2.33,
+            },
+            histogram: Histogram {
+                bins: Vec::new(),
+                bin_counts: Vec::new(),
+            },
+            boolean: None,
+            temporal: None,
+            missing_count: 0,
+            missing_rate: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_data_profile_compare() {
+        let baseline = DataProfile {
+            features: HashMap::from([
+                ("a".to_string(), feature_data_profile("a", 0.0, 100)),
+                ("b".to_string(), feature_data_profile("b", 5.0, 100)),
+                ("c".to_string(), feature_data_profile("c", 1.0, 100)),
+            ]),
+            schema_version: DATA_PROFILE_SCHEMA_VERSION,
+        };
+
+        let current = DataProfile {
+            features: HashMap::from([
+                ("a".to_string(), feature_data_profile("a", 0.1, 100)),
+                ("b".to_string(), feature_data_profile("b", 6.0, 150)),
+                ("d".to_string(), feature_data_profile("d", 1.0, 100)),
+            ]),
+            schema_version: DATA_PROFILE_SCHEMA_VERSION,
+        };
+
+        let comparison = baseline.compare(&current, None, None, None);
+
+        assert!(!comparison.features["a"].changed);
+        assert!(comparison.features["b"].changed);
+        assert_eq!(comparison.features["b"].mean_shift, 1.0);
+        assert_eq!(comparison.features["b"].distinct_change, 0.5);
+        assert_eq!(comparison.changed_features(), vec!["b".to_string()]);
+        assert_eq!(comparison.missing_features, vec!["c".to_string()]);
+        assert_eq!(comparison.new_features, vec!["d".to_string()]);
+
+        // loosen thresholds
+        let comparison = baseline.compare(&current, Some(2.0), Some(2.0), Some(1.0));
+        assert!(comparison.changed_features().is_empty());
+    }
+
+    #[test]
+    fn test_to_records() {
+        let mut drift_map =
+            DriftMap::new("name".to_string(), "repo".to_string(), "0.1.0".to_string());
+        drift_map.add_feature(
+            "feature_1".to_string(),
+            FeatureDrift {
+                samples: vec![1.0, 2.0],
+                drift: vec![0.0, 1.0],
+            },
+        );
+        drift_map.add_feature(
+            "feature_2".to_string(),
+            FeatureDrift {
+                samples: vec![3.0, 4.0],
+                drift: vec![2.0, 0.0],
+            },
+        );
+
+        let (features, samples, drift) = drift_map.to_records();
+        assert_eq!(
+            features,
+            vec!["feature_1", "feature_1", "feature_2", "feature_2"]
+        );
+        assert_eq!(samples, vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(drift, vec![0.0, 1.0, 2.0, 0.0]);
+
+        let mut alerts = FeatureAlerts::new();
+        alerts.insert_feature_alert(
+            "feature_2",
+            &HashSet::from([Alert::new("Consecutive".to_string(), "Zone 1".to_string())]),
+            &HashMap::new(),
+        );
+        alerts.insert_feature_alert("feature_1", &HashSet::new(), &HashMap::new());
+
+        let (features, kinds, zones) = alerts.to_records();
+        assert_eq!(features, vec!["feature_2"]);
+        assert_eq!(kinds, vec!["Consecutive"]);
+        assert_eq!(zones, vec!["Zone 1"]);
+    }
+
+    mod round_trip {
+        use super::*;
+        use proptest::collection::{btree_map, hash_map, vec};
+        use proptest::option;
+        use proptest::prelude::*;
+
+        fn timestamp() -> impl Strategy<Value = chrono::NaiveDateTime> {
+            (0i64..4_102_444_800, 0u32..1_000_000_000).prop_map(|(secs, nanos)| {
+                chrono::DateTime::from_timestamp(secs, nanos)
+                    .unwrap()
+                    .naive_utc()
+            })
+        }
+
+        fn finite() -> impl Strategy<Value = f64> {
+            prop::num::f64::NORMAL | prop::num::f64::ZERO | prop::num::f64::SUBNORMAL
+        }
+
+        fn name() -> impl Strategy<Value = String> {
+            "[a-zA-Z0-9_ .\\-]{0,16}"
+        }
+
+        fn drift_server_record() -> impl Strategy<Value = DriftServerRecord> {
+            (timestamp(), name(), name(), name(), name(), finite()).prop_map(
+                |(created_at, name, repository, version, feature, value)| DriftServerRecord {
+                    created_at,
+                    name,
+                    repository,
+                    version,
+                    feature,
+                    value,
+                },
+            )
+        }
+
+        fn alert_rule() -> impl Strategy<Value = AlertRule> {
+            prop_oneof![
+                "[0-9 ]{0,16}".prop_map(|rule| AlertRule {
+                    process: Some(ProcessAlertRule { rule }),
+                    percentage: None,
+                    ewma: None,
+                    cusum: None,
+                }),
+                finite().prop_map(|rule| AlertRule {
+                    process: None,
+                    percentage: Some(PercentageAlertRule { rule }),
+                    ewma: None,
+                    cusum: None,
+                }),
+                (finite(), finite()).prop_map(|(lambda, limit)| AlertRule {
+                    process: None,
+                    percentage: None,
+                    ewma: Some(EwmaAlertRule { lambda, limit }),
+                    cusum: None,
+                }),
+                (finite(), finite()).prop_map(|(k, h)| AlertRule {
+                    process: None,
+                    percentage: None,
+                    ewma: None,
+                    cusum: Some(CusumAlertRule { k, h }),
+                }),
+            ]
+        }
+
+        fn drift_config() -> impl Strategy<Value = DriftConfig> {
+            (
+                any::<usize>(),
+                any::<bool>(),
+                name(),
+                name(),
+                name(),
+                name(),
+                alert_rule(),
+            )
+                .prop_map(
+                    |(sample_size, sample, name, repository, version, schedule, alert_rule)| {
+                        DriftConfig {
+                            sample_size,
+                            sample,
+                            name,
+                            repository,
+                            version,
+                            schedule,
+                            alert_rule,
+                        }
+                    },
+                )
+        }
+
+        fn feature_drift_profile() -> impl Strategy<Value = FeatureDriftProfile> {
+            (name(), vec(finite(), 7), timestamp()).prop_map(|(id, limits, timestamp)| {
+                FeatureDriftProfile {
+                    id,
+                    center: limits[0],
+                    one_ucl: limits[1],
+                    one_lcl: limits[2],
+                    two_ucl: limits[3],
+                    two_lcl: limits[4],
+                    three_ucl: limits[5],
+                    three_lcl: limits[6],
+                    timestamp,
+                }
+            })
+        }
+
+        fn drift_profile() -> impl Strategy<Value = DriftProfile> {
+            (
+                hash_map(name(), feature_drift_profile(), 0..8),
+                drift_config(),
+                hash_map(name(), name(), 0..4),
+                hash_map(name(), finite(), 0..4),
+                hash_map(name(), "[a-z_]{1,8} [-+*/] [a-z_0-9.]{1,8}", 0..4),
+            )
+                .prop_map(
+                    |(features, config, feature_aliases, feature_weights, computed_features)| {
+                        DriftProfile {
+                            features,
+                            config,
+                            feature_aliases,
+                            feature_weights,
+                            computed_features,
+                            schema_version: DRIFT_PROFILE_SCHEMA_VERSION,
+                        }
+                    },
+                )
+        }
+
+        fn feature_data_profile() -> impl Strategy<Value = FeatureDataProfile> {
+            (
+                name(),
+                vec(finite(), 9),
+                timestamp(),
+                (any::<usize>(), vec((name(), any::<usize>()), 0..4)),
+                (vec(finite(), 0..8), vec(any::<i32>(), 0..8)),
+                option::of((any::<usize>(), any::<usize>(), any::<usize>())),
+                option::of((vec(any::<usize>(), 7), vec(any::<usize>(), 24))),
+                (any::<usize>(), finite()),
+            )
+                .prop_map(
+                    |(
+                        id,
+                        stats,
+                        timestamp,
+                        (count, top_values),
+                        (bins, bin_counts),
+                        boolean,
+                        temporal,
+                        (missing_count, missing_rate),
+                    )| {
+                        FeatureDataProfile {
+                            id,
+                            mean: stats[0],
+                            stddev: stats[1],
+                            min: stats[2],
+                            max: stats[3],
+                            timestamp,
+                            distinct: Distinct {
+                                count,
+                                percent: stats[4],
+                                top_values,
+                            },
+                            quantiles: Quantiles {
+                                q25: stats[5],
+                                q50: stats[6],
+                                q75: stats[7],
+                                q99: stats[8],
+                            },
+                            histogram: Histogram { bins, bin_counts },
+                            boolean: boolean.map(|(true_count, false_count, null_count)| {
+                                BooleanStats {
+                                    true_count,
+                                    false_count,
+                                    null_count,
+                                }
+                            }),
+                            temporal: temporal.map(|(weekday_counts, hour_counts)| TemporalStats {
+                                weekday_counts,
+                                hour_counts,
+                            }),
+                            missing_count,
+                            missing_rate,
+                        }
+                    },
+                )
+        }
+
+        fn drift_map() -> impl Strategy<Value = DriftMap> {
+            (
+                btree_map(
+                    name(),
+                    (vec(finite(), 0..8), vec(finite(), 0..8))
+                        .prop_map(|(samples, drift)| FeatureDrift { samples, drift }),
+                    0..8,
+                ),
+                name(),
+                name(),
+                name(),
+            )
+                .prop_map(|(features, name, repository, version)| DriftMap {
+                    features,
+                    name,
+                    repository,
+                    version,
+                })
+        }
+
+        // compare through serde_json::Value so hashmap ordering does not matter
+        fn assert_round_trip<T: Serialize>(original: &T, json: &str, loaded: &T) {
+            assert_eq!(
+                serde_json::to_value(original).unwrap(),
+                serde_json::to_value(loaded).unwrap(),
+                "round trip changed {}",
+                json
+            );
+        }
+
+        proptest! {
+            #[test]
+            fn drift_server_record_json(record in drift_server_record()) {
+                let json = record.model_dump_json();
+                let loaded: DriftServerRecord = serde_json::from_str(&json).unwrap();
+                assert_round_trip(&record, &json, &loaded);
+            }
+
+            #[test]
+            fn drift_server_records_json(records in vec(drift_server_record(), 0..16)) {
+                let json = serde_json::to_string(&records).unwrap();
+                let loaded: Vec<DriftServerRecord> = serde_json::from_str(&json).unwrap();
+                assert_round_trip(&records, &json, &loaded);
+            }
+
+            // to_dict is the encoding used by the http and local producers
+            #[test]
+            fn drift_server_record_dict(record in drift_server_record()) {
+                let dict = record.to_dict();
+                let created_at = chrono::NaiveDateTime::parse_from_str(
+                    &dict["created_at"],
+                    "%Y-%m-%d %H:%M:%S%.f",
+                )
+                .unwrap();
+
+                prop_assert_eq!(created_at, record.created_at);
+                prop_assert_eq!(dict["value"].parse::<f64>().unwrap(), record.value);
+                prop_assert_eq!(&dict["feature"], &record.feature);
+            }
+
+            #[test]
+            fn drift_profile_json(profile in drift_profile()) {
+                let json = profile.model_dump_json();
+                let loaded = DriftProfile::load_from_json(json.clone());
+                assert_round_trip(&profile, &json, &loaded);
+            }
+
+            #[test]
+            fn data_profile_json(features in hash_map(name(), feature_data_profile(), 0..8)) {
+                let profile = DataProfile {
+                    features,
+                    schema_version: DATA_PROFILE_SCHEMA_VERSION,
+                };
+                let json = profile.model_dump_json();
+                let loaded = DataProfile::load_from_json(json.clone());
+                assert_round_trip(&profile, &json, &loaded);
+            }
+
+            #[test]
+            fn drift_map_json(drift_map in drift_map()) {
+                let json = drift_map.model_dump_json();
+                let loaded = DriftMap::load_from_json(json.clone());
+                assert_round_trip(&drift_map, &json, &loaded);
+            }
+        }
+
+        // profiles saved by previous releases must keep loading
+        #[test]
+        fn corpus_drift_profile_v0_2_0() {
+            let profile = DriftProfile::load_from_json(
+                include_str!("../../tests/corpus/drift_profile_v0_2_0.json").to_string(),
+            );
+
+            assert_eq!(profile.features.len(), 2);
+            assert_eq!(profile.config.sample_size, 25);
+            assert!(profile.feature_aliases.is_empty());
+            assert!(profile.feature_weights.is_empty());
+            assert!(profile.computed_features.is_empty());
+            assert_eq!(profile.schema_version, DRIFT_PROFILE_SCHEMA_VERSION);
+        }
+
+        #[test]
+        fn corpus_data_profile_v0_2_0() {
+            let profile = DataProfile::load_from_json(
+                include_str!("../../tests/corpus/data_profile_v0_2_0.json").to_string(),
+            );
+
+            let feature = &profile.features["feature_0"];
+            assert_eq!(feature.distinct.count, 1000);
+            assert!(feature.distinct.top_values.is_empty());
+            assert_eq!(profile.schema_version, DATA_PROFILE_SCHEMA_VERSION);
+        }
+
+        #[test]
+        fn corpus_drift_server_record_v0_2_0() {
+            let record: DriftServerRecord = serde_json::from_str(include_str!(
+                "../../tests/corpus/drift_server_record_v0_2_0.json"
+            ))
+            .unwrap();
+
+            assert_eq!(record.feature, "feature_0");
+            assert_eq!(record.value, 1.5);
+        }
     }
 }
@@ -1,19 +1,27 @@
 # pylint: disable=no-name-in-module
 
 # Integrations
+from scouter.integrations.base import BaseProducer
 from scouter.integrations.http import HTTPConfig, HTTPProducer
 from scouter.integrations.kafka import KafkaConfig, KafkaProducer
+from scouter.integrations.local import LocalConfig, LocalProducer
 from scouter.integrations.producer import DriftRecordProducer
-from scouter.utils.types import AlertType, AlertZone
+from scouter.utils.types import AlertType, AlertZone, SamplingStrategy
 
 from ._scouter import (
     Alert,
     AlertRule,
+    BooleanStats,
+    CronSchedule,
+    CusumAlertRule,
     DataProfile,
+    DataProfileComparison,
     DriftConfig,
     DriftMap,
     DriftProfile,
+    DriftScore,
     DriftServerRecord,
+    EwmaAlertRule,
     Every6Hours,
     Every12Hours,
     Every30Minutes,
@@ -23,8 +31,10 @@ from ._scouter import (
     FeatureAlerts,
     FeatureDataProfile,
     FeatureDriftProfile,
+    FeatureProfileChange,
     PercentageAlertRule,
     ProcessAlertRule,
+    TemporalStats,
 )
 from .scouter import CommonCrons, Drifter, MonitorQueue, Profiler
 from .version import __version__
@@ -34,9 +44,14 @@ __all__ = [
     "Drifter",
     "__version__",
     "DataProfile",
+    "DataProfileComparison",
+    "FeatureProfileChange",
     "DriftProfile",
+    "DriftScore",
     "FeatureDriftProfile",
     "FeatureDataProfile",
+    "BooleanStats",
+    "TemporalStats",
     "Alert",
     "AlertType",
     "AlertRule",
@@ -44,20 +59,27 @@ __all__ = [
     "FeatureAlerts",
     "ProcessAlertRule",
     "PercentageAlertRule",
+    "EwmaAlertRule",
+    "CusumAlertRule",
     "Every30Minutes",
     "EveryHour",
     "Every6Hours",
     "Every12Hours",
     "EveryDay",
     "EveryWeek",
+    "CronSchedule",
     "DriftConfig",
     "DriftMap",
     "CommonCrons",
     "MonitorQueue",
+    "SamplingStrategy",
     "DriftServerRecord",
     "KafkaConfig",
     "KafkaProducer",
     "HTTPConfig",
     "HTTPProducer",
+    "LocalConfig",
+    "LocalProducer",
+    "BaseProducer",
     "DriftRecordProducer",
 ]
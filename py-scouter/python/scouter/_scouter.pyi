@@ -4,6 +4,8 @@ import datetime
 from pathlib import Path
 from typing import Dict, List, Optional, Tuple, Union
 
+import pandas as pd
+import polars as pl
 from numpy.typing import NDArray
 
 class DriftServerRecord:
@@ -118,6 +120,31 @@ class CommonCron:
     def EVERY_WEEK(self) -> str:
         """Every week cron schedule"""
 
+class CronSchedule:
+    def __init__(self, cron: str) -> None:
+        """Validate a cron expression. Raises a ValueError if the expression is
+        invalid or has no upcoming run times.
+
+        Args:
+            cron:
+                Cron expression (sec min hour day-of-month month day-of-week [year])
+        """
+    @property
+    def cron(self) -> str:
+        """Return the cron schedule"""
+    def get_next(self) -> Optional[str]:
+        """Return the next run time in UTC, or None once the schedule has no upcoming runs"""
+    def preview(self, count: int = 5) -> List[str]:
+        """Preview the next run times of the schedule
+
+        Args:
+            count:
+                Number of upcoming run times to return. Defaults to 5.
+
+        Returns:
+            Upcoming run times in UTC
+        """
+
 class PercentageAlertRule:
     def __init__(self, rule: Optional[float] = None) -> None:
         """Initialize alert rule
@@ -130,6 +157,53 @@ class PercentageAlertRule:
     def rule(self) -> float:
         """Return the alert rule"""
 
+class EwmaAlertRule:
+    def __init__(self, lambda_: Optional[float] = None, limit: Optional[float] = None) -> None:
+        """Initialize exponentially weighted moving average (EWMA) chart rule.
+        Raises a ValueError if a parameter is out of range.
+
+        The EWMA statistic is not kept between drift computations. Each call to
+        compute_drift starts from the profile center, so compute drift over a
+        window of consecutive samples.
+
+        Args:
+            lambda_:
+                Weight given to the most recent sample, between 0 (exclusive) and 1.
+                Defaults to 0.2
+            limit:
+                Width of the control limits in standard deviations of the EWMA statistic.
+                Defaults to 3.0
+        """
+    @property
+    def lambda_(self) -> float:
+        """Return the EWMA weight"""
+    @property
+    def limit(self) -> float:
+        """Return the control limit width"""
+
+class CusumAlertRule:
+    def __init__(self, k: Optional[float] = None, h: Optional[float] = None) -> None:
+        """Initialize cumulative sum (CUSUM) chart rule.
+        Raises a ValueError if a parameter is out of range.
+
+        The cumulative sums are not kept between drift computations. Each call to
+        compute_drift starts from zero, so compute drift over a window of
+        consecutive samples.
+
+        Args:
+            k:
+                Allowance in sigma units of the profile, typically half the shift
+                to detect. Defaults to 0.5
+            h:
+                Decision interval in sigma units of the profile. Defaults to 5.0
+        """
+    @property
+    def k(self) -> float:
+        """Return the allowance"""
+    @property
+    def h(self) -> float:
+        """Return the decision interval"""
+
 class ProcessAlertRule:
     def __init__(self, rule: Optional[str] = None) -> None:
         """Initialize alert rule
@@ -148,12 +222,22 @@ class AlertRule:
         self,
         percentage_rule: Optional[PercentageAlertRule] = None,
         process_rule: Optional[ProcessAlertRule] = None,
+        ewma_rule: Optional[EwmaAlertRule] = None,
+        cusum_rule: Optional[CusumAlertRule] = None,
     ) -> None:
         """Initialize alert rule
 
         Args:
-            rule:
-                Rule to use for alerting.
+            percentage_rule:
+                Percentage rule to use for alerting.
+            process_rule:
+                Process control (zone) rule to use for alerting.
+            ewma_rule:
+                EWMA chart rule to use for alerting.
+            cusum_rule:
+                CUSUM chart rule to use for alerting.
+
+        Defaults to the process rule if no rule is provided.
         """
     @property
     def process(self) -> Optional[ProcessAlertRule]:
@@ -161,6 +245,12 @@ class AlertRule:
     @property
     def percentage(self) -> Optional[PercentageAlertRule]:
         """Return the percentage alert rule"""
+    @property
+    def ewma(self) -> Optional[EwmaAlertRule]:
+        """Return the EWMA alert rule"""
+    @property
+    def cusum(self) -> Optional[CusumAlertRule]:
+        """Return the CUSUM alert rule"""
 
 class Alert:
     def __init__(self, alert_type: str, zone: str):
@@ -187,6 +277,10 @@ class FeatureAlerts:
     @property
     def features(self) -> Dict[str, FeatureAlert]:
         """Return the feature alerts."""
+    def to_pandas(self) -> pd.DataFrame:
+        """Return alerts as a pandas dataframe with columns feature, kind and zone"""
+    def to_polars(self) -> pl.DataFrame:
+        """Return alerts as a polars dataframe with columns feature, kind and zone"""
 
 class FeatureDriftProfile:
     @property
@@ -242,7 +336,9 @@ class DriftConfig:
             sample_size:
                 Sample size
             schedule:
-                Schedule to run monitor. Defaults to daily at midnight
+                Schedule to run monitor. Defaults to daily at midnight. Invalid cron
+                expressions, or expressions without upcoming run times, log a warning and
+                fall back to the default schedule
             alert_rule:
                 Alert rule to use. Defaults to Standard
         """
@@ -275,6 +371,38 @@ class DriftProfile:
     @property
     def config(self) -> DriftConfig:
         """Return the monitor config."""
+    @property
+    def feature_aliases(self) -> Dict[str, str]:
+        """Alternative feature names mapped to features in the profile."""
+    @property
+    def feature_weights(self) -> Dict[str, float]:
+        """Feature weights (e.g. feature importance) used for the model-level drift score."""
+    @feature_weights.setter
+    def feature_weights(self, feature_weights: Dict[str, float]) -> None:
+        """Set the feature weights."""
+    @property
+    def computed_features(self) -> Dict[str, str]:
+        """Computed feature names mapped to the expressions used to compute them."""
+    @computed_features.setter
+    def computed_features(self, computed_features: Dict[str, str]) -> None:
+        """Set the computed features."""
+    @property
+    def schema_version(self) -> int:
+        """Schema version of the profile. Profiles saved with older versions
+        are upgraded when loaded."""
+    def add_feature_alias(self, alias: str, feature: str) -> None:
+        """Map an alternative feature name (e.g. after an upstream rename) to a feature
+        in the profile. Records and drift for the alias are reported under the profile feature.
+
+        Raises a ValueError if the feature is not in the profile or the alias is itself a
+        profile feature, so aliases never chain.
+
+        Args:
+            alias:
+                Alternative feature name.
+            feature:
+                Feature name in the profile.
+        """
     def __str__(self) -> str:
         """Sting representation of DriftProfile"""
 
@@ -285,6 +413,9 @@ class Distinct:
     @property
     def percent(self) -> float:
         """percent value uniqueness"""
+    @property
+    def top_values(self) -> List[Tuple[str, int]]:
+        """Most frequent values and their counts"""
 
 class Quantiles:
     @property
@@ -308,6 +439,25 @@ class Histogram:
     def bin_counts(self) -> List[int]:
         """Bin counts"""
 
+class BooleanStats:
+    @property
+    def true_count(self) -> int:
+        """Number of true values"""
+    @property
+    def false_count(self) -> int:
+        """Number of false values"""
+    @property
+    def null_count(self) -> int:
+        """Number of null values"""
+
+class TemporalStats:
+    @property
+    def weekday_counts(self) -> List[int]:
+        """Number of values per day of the week (UTC), starting on Monday"""
+    @property
+    def hour_counts(self) -> List[int]:
+        """Number of values per hour of the day (UTC)"""
+
 class FeatureDataProfile:
     @property
     def id(self) -> str:
@@ -336,6 +486,18 @@ class FeatureDataProfile:
     @property
     def histogram(self) -> Histogram:
         """Value histograms"""
+    @property
+    def boolean(self) -> Optional[BooleanStats]:
+        """True/false/null counts for boolean features"""
+    @property
+    def temporal(self) -> Optional[TemporalStats]:
+        """Weekday and hour distributions for datetime features"""
+    @property
+    def missing_count(self) -> int:
+        """Number of missing values. Only boolean and datetime features can have missing values"""
+    @property
+    def missing_rate(self) -> float:
+        """Fraction of missing values"""
 
 class DataProfile:
     """Data profile of features"""
@@ -343,6 +505,10 @@ class DataProfile:
     @property
     def features(self) -> Dict[str, FeatureDataProfile]:
         """Returns dictionary of features and their data profiles"""
+    @property
+    def schema_version(self) -> int:
+        """Schema version of the data profile. Profiles saved with older versions
+        are upgraded when loaded."""
     def __str__(self) -> str:
         """Return string representation of the data profile"""
     def model_dump_json(self) -> str:
@@ -358,6 +524,65 @@ class DataProfile:
                 Optional path to save the data profile. If None, outputs to "data_profile.json.
 
         """
+    def compare(
+        self,
+        other: "DataProfile",
+        mean_threshold: Optional[float] = None,
+        quantile_threshold: Optional[float] = None,
+        distinct_threshold: Optional[float] = None,
+    ) -> "DataProfileComparison":
+        """Compare this (baseline) data profile to another data profile
+
+        Args:
+            other:
+                Data profile to compare against, e.g. a profile of serving data
+            mean_threshold:
+                Max mean shift in baseline standard deviations. Defaults to 0.25
+            quantile_threshold:
+                Max quantile shift in baseline standard deviations. Defaults to 0.5
+            distinct_threshold:
+                Max relative change in distinct count. Defaults to 0.1
+
+        Returns:
+            DataProfileComparison
+        """
+
+class FeatureProfileChange:
+    @property
+    def feature(self) -> str:
+        """Feature name"""
+    @property
+    def mean_shift(self) -> float:
+        """Change in mean, in baseline standard deviations"""
+    @property
+    def stddev_ratio(self) -> float:
+        """Ratio of the compared stddev to the baseline stddev"""
+    @property
+    def quantile_shift(self) -> float:
+        """Largest absolute quantile change, in baseline standard deviations"""
+    @property
+    def distinct_change(self) -> float:
+        """Relative change in distinct count"""
+    @property
+    def changed(self) -> bool:
+        """Whether any shift exceeded its threshold"""
+
+class DataProfileComparison:
+    @property
+    def features(self) -> Dict[str, FeatureProfileChange]:
+        """Per-feature changes for features present in both profiles"""
+    @property
+    def new_features(self) -> List[str]:
+        """Features only present in the compared profile"""
+    @property
+    def missing_features(self) -> List[str]:
+        """Features only present in the baseline profile"""
+    def changed_features(self) -> List[str]:
+        """Names of features whose shifts exceeded a threshold"""
+    def __str__(self) -> str:
+        """Return string representation of the comparison"""
+    def model_dump_json(self) -> str:
+        """Return json representation of the comparison"""
 
 class FeatureDrift:
     @property
@@ -369,6 +594,18 @@ class FeatureDrift:
     def __str__(self) -> str:
         """Return string representation of feature drift"""
 
+class DriftScore:
+    @property
+    def score(self) -> float:
+        """Weighted drift score between 0 and 1, averaged over all samples"""
+    @property
+    def trend(self) -> List[float]:
+        """Weighted drift score for each sample"""
+    def __str__(self) -> str:
+        """Return string representation of the drift score"""
+    def model_dump_json(self) -> str:
+        """Return json representation of the drift score"""
+
 class DriftMap:
     """Drift map of features"""
 
@@ -408,6 +645,10 @@ class DriftMap:
         """
     def to_numpy(self) -> Tuple[NDArray, List[str]]:
         """Return drift map as a numpy array and list of features"""
+    def to_pandas(self) -> pd.DataFrame:
+        """Return drift map as a pandas dataframe with columns feature, sample and drift"""
+    def to_polars(self) -> pl.DataFrame:
+        """Return drift map as a polars dataframe with columns feature, sample and drift"""
     def to_service_record(self) -> List[DriftServerRecord]:
         """Return drift map as a drift server record"""
 
@@ -420,6 +661,9 @@ class ScouterProfiler:
         features: List[str],
         array: NDArray,
         bin_size: int,
+        approx_distinct: bool = False,
+        boolean_features: Optional[List[str]] = None,
+        datetime_features: Optional[List[str]] = None,
     ) -> DataProfile:
         """Create a data profile from a f32 numpy array.
 
@@ -430,6 +674,12 @@ class ScouterProfiler:
                 Numpy array to profile.
             bin_size:
                 Optional bin size for histograms. Defaults to 20 bins.
+            approx_distinct:
+                Whether to use approximate distinct counting. Defaults to False.
+            boolean_features:
+                Features holding booleans encoded as 0/1. These also get true/false counts.
+            datetime_features:
+                Features holding seconds since epoch. These also get weekday and hour counts.
 
         Returns:
             Monitoring profile.
@@ -439,6 +689,9 @@ class ScouterProfiler:
         features: List[str],
         array: NDArray,
         bin_size: int,
+        approx_distinct: bool = False,
+        boolean_features: Optional[List[str]] = None,
+        datetime_features: Optional[List[str]] = None,
     ) -> DataProfile:
         """Create a data profile from a f32 numpy array.
 
@@ -449,6 +702,12 @@ class ScouterProfiler:
                 Numpy array to profile.
             bin_size:
                 Optional bin size for histograms. Defaults to 20 bins.
+            approx_distinct:
+                Whether to use approximate distinct counting. Defaults to False.
+            boolean_features:
+                Features holding booleans encoded as 0/1. These also get true/false counts.
+            datetime_features:
+                Features holding seconds since epoch. These also get weekday and hour counts.
 
         Returns:
             Monitoring profile.
@@ -594,3 +853,92 @@ class ScouterDrifter:
         Returns:
             List of server records
         """
+    def compute_drift_score(self, drift_map: DriftMap, drift_profile: DriftProfile) -> DriftScore:
+        """Aggregate feature drift into a model-level drift score.
+
+        Args:
+            drift_map:
+                Drift map computed from the drift profile.
+            drift_profile:
+                Monitoring profile.
+
+        Returns:
+            DriftScore
+        """
+    def sample_batches_f64(
+        self,
+        features: List[str],
+        arrays: List[NDArray],
+        drift_profile: DriftProfile,
+    ) -> List[DriftServerRecord]:
+        """Sample data from a list of f64 numpy arrays in parallel.
+
+        Args:
+            features:
+                List of feature names. Must match the column order of each array.
+            arrays:
+                List of numpy arrays (e.g. converted record batches).
+            drift_profile:
+                Monitoring profile.
+
+        Returns:
+            List of server records
+        """
+    def estimate_false_positive_rate_f32(
+        self,
+        features: List[str],
+        array: NDArray,
+        drift_profile: DriftProfile,
+        num_windows: int = 1000,
+        window_size: int = 10,
+        seed: Optional[int] = None,
+    ) -> Dict[str, float]:
+        """Estimate the false positive alert rate of a drift profile per feature by
+        bootstrapping monitoring windows from a f32 numpy array of baseline data.
+
+        Args:
+            features:
+                List of feature names. Must match the column order of the array.
+            array:
+                Baseline numpy array.
+            drift_profile:
+                Monitoring profile.
+            num_windows:
+                Number of bootstrapped windows.
+            window_size:
+                Number of samples in each window.
+            seed:
+                Optional seed for reproducible estimates.
+
+        Returns:
+            Dictionary of feature names and false positive rates
+        """
+    def estimate_false_positive_rate_f64(
+        self,
+        features: List[str],
+        array: NDArray,
+        drift_profile: DriftProfile,
+        num_windows: int = 1000,
+        window_size: int = 10,
+        seed: Optional[int] = None,
+    ) -> Dict[str, float]:
+        """Estimate the false positive alert rate of a drift profile per feature by
+        bootstrapping monitoring windows from a f64 numpy array of baseline data.
+
+        Args:
+            features:
+                List of feature names. Must match the column order of the array.
+            array:
+                Baseline numpy array.
+            drift_profile:
+                Monitoring profile.
+            num_windows:
+                Number of bootstrapped windows.
+            window_size:
+                Number of samples in each window.
+            seed:
+                Optional seed for reproducible estimates.
+
+        Returns:
+            Dictionary of feature names and false positive rates
+        """
@@ -4,14 +4,31 @@ from .._scouter import DriftServerRecord
 
 
 class BaseProducer:
-    """Base class for all producers."""
+    """Base class for all producers.
+
+    Subclass to publish drift records to a transport that scouter does not support
+    out of the box and pass an instance as the config of a MonitorQueue.
+    """
 
     def publish(self, record: DriftServerRecord) -> None:
+        """Publishes a drift record.
+
+        Args:
+            record:
+                Drift record to publish.
+        """
         raise NotImplementedError
 
     def flush(self, timeout: Optional[float] = None) -> None:
+        """Flushes any buffered records.
+
+        Args:
+            timeout:
+                Optional timeout in seconds.
+        """
         raise NotImplementedError
 
     @staticmethod
     def type() -> str:
+        """Name of the producer type."""
         raise NotImplementedError
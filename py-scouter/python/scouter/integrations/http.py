@@ -1,17 +1,67 @@
+import uuid
 from enum import Enum
 from typing import Any, Dict, Optional, cast
 
 import httpx
+import tenacity
 from pydantic import BaseModel
 from scouter.integrations.base import BaseProducer
+from scouter.utils.circuit_breaker import (
+    CircuitBreaker,
+    CircuitBreakerOpenError,
+    CircuitState,
+)
 from scouter.utils.logger import ScouterLogger
 from scouter.utils.types import ProducerTypes
-from tenacity import retry, stop_after_attempt
 
 from .._scouter import DriftServerRecord
 
 logger = ScouterLogger.get_logger()
 MESSAGE_MAX_BYTES_DEFAULT = 2097164
+REQUEST_ID_HEADER = "x-request-id"
+
+
+class ServerResponseError(ValueError):
+    """Raised when the server responds with an unsuccessful status code."""
+
+    def __init__(self, message: str, status_code: int, request_id: str) -> None:
+        super().__init__(message)
+        self.status_code = status_code
+        self.request_id = request_id
+
+
+class ServerConnectionError(ValueError):
+    """Raised when the server cannot be reached or does not respond in time."""
+
+    def __init__(self, message: str, request_id: str) -> None:
+        super().__init__(message)
+        self.request_id = request_id
+
+
+def _response_detail(response: httpx.Response) -> Any:
+    """Error detail of a response. Falls back to the raw body for non-JSON replies (e.g. a 502 from a proxy)."""
+    try:
+        body = response.json()
+    except ValueError:
+        return response.text
+
+    if isinstance(body, dict) and "detail" in body:
+        return body["detail"]
+    return body
+
+
+def _is_server_unavailable(exc: BaseException) -> bool:
+    """Whether an error means the server is unreachable or failing, rather than rejecting the request."""
+    if isinstance(exc, (httpx.TransportError, ServerConnectionError)):
+        return True
+    return isinstance(exc, ServerResponseError) and exc.status_code >= 500
+
+
+def _is_retryable(exc: BaseException) -> bool:
+    # unauthorized responses are retried once the token has been refreshed
+    if isinstance(exc, ServerResponseError) and exc.status_code == 401:
+        return True
+    return _is_server_unavailable(exc)
 
 
 class RequestType(str, Enum):
@@ -25,10 +75,50 @@ class ApiRoutes:
 
 
 class HTTPConfig(BaseModel):
+    """HTTP configuration to use with the HTTPProducer.
+
+    Args:
+        server_url:
+            Url of the scouter server.
+
+        username:
+            Username to authenticate with.
+
+        password:
+            Password to authenticate with.
+
+        max_retries:
+            Maximum number of retries after the first attempt of a request. Only server errors (5xx),
+            transport errors (connection failures, resets and timeouts) and expired tokens are retried.
+            Default is 3.
+
+        retry_initial_wait:
+            Initial wait in seconds between attempts. Doubles with each attempt and jitter is added.
+            Default is 1.
+
+        retry_max_wait:
+            Maximum wait in seconds between attempts.
+            Default is 16.
+
+        circuit_failure_threshold:
+            Number of consecutive requests failing with server errors or transport errors
+            before requests are short-circuited.
+            Default is 5.
+
+        circuit_reset_timeout:
+            Seconds to short-circuit requests before trying the server again.
+            Default is 30.
+    """
+
     server_url: str
     username: str
     password: str
     token: str = "empty"
+    max_retries: int = 3
+    retry_initial_wait: float = 1.0
+    retry_max_wait: float = 16.0
+    circuit_failure_threshold: int = 5
+    circuit_reset_timeout: float = 30.0
 
     @property
     def type(self) -> str:
@@ -48,6 +138,10 @@ class HTTPProducer(BaseProducer):
 
         """
         self._config = config
+        self.circuit_breaker = CircuitBreaker(
+            failure_threshold=config.circuit_failure_threshold,
+            reset_timeout=config.circuit_reset_timeout,
+        )
         self.client = httpx.Client()
         self.form_data = {
             "username": self._config.username,
@@ -55,6 +149,7 @@ class HTTPProducer(BaseProducer):
         }
         self._refresh_token()
         self.client.timeout = _TIMEOUT_CONFIG
+        self.last_request_id: Optional[str] = None
 
     def _refresh_token(self) -> None:
         """Refreshes bearer token."""
@@ -71,9 +166,22 @@ class HTTPProducer(BaseProducer):
         self._auth_token = res["access_token"]
         self.client.headers["Authorization"] = f"Bearer {self._auth_token}"
 
-    @retry(reraise=True, stop=stop_after_attempt(3))
+    @property
+    def circuit_state(self) -> CircuitState:
+        """State of the circuit breaker. Open means requests are being short-circuited."""
+        return self.circuit_breaker.state
+
     def request(self, route: str, request_type: RequestType, **kwargs: Any) -> Dict[str, Any]:
-        """Makes a request to the server
+        """Makes a request to the server, retrying with exponential backoff and jitter.
+
+        Server errors (5xx) and transport errors (connection failures, resets and timeouts) are
+        retried and count towards opening the circuit breaker, whether or not the server replied
+        with a JSON body. Other errors are raised immediately. Requests are short-circuited
+        without calling the server while the circuit breaker is open.
+
+        Every attempt of a request is sent with the same `x-request-id` header so retries can be
+        correlated with server logs. The id is attached to raised errors as `request_id` and the
+        id of the last successful request is available as `last_request_id`.
 
         Args:
             route:
@@ -85,21 +193,76 @@ class HTTPProducer(BaseProducer):
 
         Returns:
             Response from server
+
+        Raises:
+            CircuitBreakerOpenError: When the circuit breaker is open.
+            ServerResponseError: When the server responds with an unsuccessful status code.
+            ServerConnectionError: When the server cannot be reached or times out.
         """
+        request_id = str(uuid.uuid4())
+
+        if not self.circuit_breaker.allow_request():
+            raise CircuitBreakerOpenError(
+                f"Circuit breaker is open after {self.circuit_breaker.failure_count} failed requests. "
+                f"Skipping {request_type} request Url: {route}. Request id: {request_id}",
+                request_id=request_id,
+            )
+
+        retrier = tenacity.Retrying(
+            reraise=True,
+            stop=tenacity.stop_after_attempt(self._config.max_retries + 1),
+            wait=tenacity.wait_exponential_jitter(
+                initial=self._config.retry_initial_wait,
+                max=self._config.retry_max_wait,
+            ),
+            retry=tenacity.retry_if_exception(_is_retryable),
+        )
+
+        try:
+            response = retrier(self._request, route, request_type, request_id, **kwargs)
+        except Exception as exc:
+            if _is_server_unavailable(exc):
+                self.circuit_breaker.record_failure()
+            else:
+                self.circuit_breaker.release()
+            raise exc
+
+        self.circuit_breaker.record_success()
+        self.last_request_id = request_id
+        return response
+
+    def _request(
+        self,
+        route: str,
+        request_type: RequestType,
+        request_id: str,
+        **kwargs: Any,
+    ) -> Dict[str, Any]:
+        url = f"{self._config.server_url}/{route}"
+        headers = {**kwargs.pop("headers", {}), REQUEST_ID_HEADER: request_id}
+
         try:
-            url = f"{self._config.server_url}/{route}"
-            response = getattr(self.client, request_type.value.lower())(url=url, **kwargs)
+            response = getattr(self.client, request_type.value.lower())(url=url, headers=headers, **kwargs)
+        except httpx.TransportError as exc:
+            raise ServerConnectionError(
+                f"Failed to reach server for {request_type} request Url: {route}, {exc}. Request id: {request_id}",
+                request_id=request_id,
+            ) from exc
 
-            if response.status_code == 200:
-                return cast(Dict[str, Any], response.json())
+        if response.status_code == 200:
+            return cast(Dict[str, Any], response.json())
 
-            detail = response.json().get("detail")
+        if response.status_code == 401:
             self._refresh_token()
 
-            raise ValueError(f"""Failed to make server call for {request_type} request Url: {route}, {detail}""")
+        detail = _response_detail(response)
 
-        except Exception as exc:
-            raise exc
+        raise ServerResponseError(
+            f"Failed to make server call for {request_type} request Url: {route}, {detail}. "
+            f"Request id: {request_id}",
+            status_code=response.status_code,
+            request_id=request_id,
+        )
 
     def publish(self, record: DriftServerRecord) -> None:
         """Publishes drift record to a kafka topic with retries.
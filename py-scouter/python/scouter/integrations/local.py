@@ -0,0 +1,153 @@
+import json
+import os
+from pathlib import Path
+from typing import List, Optional
+
+from pydantic import BaseModel, Field
+from scouter.integrations.base import BaseProducer
+from scouter.integrations.http import (
+    ApiRoutes,
+    HTTPConfig,
+    HTTPProducer,
+    RequestType,
+    ServerResponseError,
+)
+from scouter.utils.logger import ScouterLogger
+from scouter.utils.types import ProducerTypes
+
+from .._scouter import DriftServerRecord
+
+logger = ScouterLogger.get_logger()
+
+RECORD_FILE = "drift_records.jsonl"
+FAILED_RECORD_FILE = "drift_records.failed.jsonl"
+
+
+def _is_rejected(exc: BaseException) -> bool:
+    """Whether the server permanently rejected a record. Retrying a rejected record fails the same way."""
+    return isinstance(exc, ServerResponseError) and 400 <= exc.status_code < 500 and exc.status_code not in (401, 429)
+
+
+class LocalConfig(BaseModel):
+    """Local configuration to use with the LocalProducer.
+
+    Args:
+        path:
+            Directory to write drift records to.
+            If not provided, the value of the SCOUTER_LOCAL_PATH environment variable is used,
+            falling back to "scouter_records".
+    """
+
+    path: Path = Field(default_factory=lambda: Path(os.getenv("SCOUTER_LOCAL_PATH", "scouter_records")))
+
+    @property
+    def type(self) -> str:
+        return ProducerTypes.Local.value
+
+
+class LocalProducer(BaseProducer):
+    def __init__(self, config: LocalConfig) -> None:
+        """Producer that appends drift records to a local jsonl file instead of sending
+        them to a server. Useful for air-gapped environments. Records can be uploaded
+        later with `sync`.
+
+        Args:
+            config:
+                Local configuration to use.
+        """
+        self._config = config
+        self._config.path.mkdir(parents=True, exist_ok=True)
+        self._record_path = self._config.path / RECORD_FILE
+
+    def publish(self, record: DriftServerRecord) -> None:
+        """Appends a drift record to the local record file.
+
+        Args:
+            record:
+                Drift record to write.
+        """
+        with self._record_path.open("a", encoding="utf-8") as file:
+            file.write(json.dumps(record.to_dict()) + "\n")
+
+    def flush(self, timeout: Optional[float] = None) -> None:
+        """Flushes the producer"""
+        logger.info("Flushing not needed for local producer. Records are written on publish.")
+
+    def sync(self, config: HTTPConfig) -> int:
+        """Uploads all locally stored drift records to the scouter server.
+
+        Records are moved aside before uploading so that records published during a sync
+        are kept for the next one. Records left behind by an interrupted sync are uploaded first.
+
+        When the server is unavailable, the sync stops and the remaining records are written
+        back to be retried on the next sync. Records the server rejects (4xx other than 401 and
+        429) and malformed records are moved to `drift_records.failed.jsonl` instead, so they
+        do not block later syncs.
+
+        Args:
+            config:
+                HTTP configuration of the scouter server.
+
+        Returns:
+            Number of records uploaded.
+        """
+        syncing_path = self._record_path.with_suffix(".syncing")
+
+        if syncing_path.exists():
+            # an interrupted sync left records behind, append new records instead of replacing them
+            if self._record_path.exists():
+                pending_path = self._record_path.with_suffix(".pending")
+                self._record_path.rename(pending_path)
+                with syncing_path.open("a", encoding="utf-8") as file:
+                    file.write(pending_path.read_text(encoding="utf-8"))
+                pending_path.unlink()
+
+        elif self._record_path.exists():
+            self._record_path.rename(syncing_path)
+
+        else:
+            return 0
+
+        records = [line for line in syncing_path.read_text(encoding="utf-8").splitlines() if line]
+
+        producer = HTTPProducer(config)
+        uploaded = 0
+        processed = 0
+        rejected: List[str] = []
+
+        try:
+            for line in records:
+                try:
+                    producer.request(
+                        route=ApiRoutes.INSERT,
+                        request_type=RequestType.POST,
+                        json=json.loads(line),
+                    )
+                    uploaded += 1
+
+                except (json.JSONDecodeError, ServerResponseError) as exc:
+                    if isinstance(exc, ServerResponseError) and not _is_rejected(exc):
+                        raise exc
+                    logger.error(f"Drift record rejected, moving it to {FAILED_RECORD_FILE}: {exc}")
+                    rejected.append(line)
+
+                processed += 1
+
+        finally:
+            if rejected:
+                with (self._config.path / FAILED_RECORD_FILE).open("a", encoding="utf-8") as file:
+                    file.write("\n".join(rejected) + "\n")
+
+            remaining = records[processed:]
+            if remaining:
+                logger.error(f"Failed to sync {len(remaining)} drift records. Records will be retried on next sync.")
+                with self._record_path.open("a", encoding="utf-8") as file:
+                    file.write("\n".join(remaining) + "\n")
+            syncing_path.unlink()
+
+        logger.info(f"Synced {uploaded} drift records to {config.server_url}")
+        return uploaded
+
+    @staticmethod
+    def type() -> str:
+        return ProducerTypes.Local.value
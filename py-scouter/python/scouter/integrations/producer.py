@@ -3,6 +3,7 @@ from typing import Union
 from scouter.integrations.base import BaseProducer
 from scouter.integrations.http import HTTPConfig, HTTPProducer
 from scouter.integrations.kafka import KafkaConfig, KafkaProducer
+from scouter.integrations.local import LocalConfig, LocalProducer
 from scouter.utils.logger import ScouterLogger
 from scouter.utils.types import ProducerTypes
 
@@ -13,22 +14,32 @@ class DriftRecordProducer:
     """Helper class to get the producer based on the producer type"""
 
     @staticmethod
-    def get_producer(config: Union[HTTPConfig, KafkaConfig]) -> BaseProducer:
+    def get_producer(config: Union[HTTPConfig, KafkaConfig, LocalConfig, BaseProducer]) -> BaseProducer:
         """Gets the producer based on the producer type
 
         Args:
             config:
-                Configuration for the producer
+                Configuration for the producer, or a user-provided producer instance
 
         Returns:
             BaseProducer: Producer instance
         """
-        if not isinstance(config, (HTTPConfig, KafkaConfig)):
-            raise ValueError(f"config must be an instance of either HTTPConfig or KafkaConfig, got {type(config)}")
+        if isinstance(config, BaseProducer):
+            return config
+
+        if not isinstance(config, (HTTPConfig, KafkaConfig, LocalConfig)):
+            raise ValueError(
+                "config must be an instance of either HTTPConfig, KafkaConfig, LocalConfig or BaseProducer, "
+                f"got {type(config)}"
+            )
 
         if config.type == ProducerTypes.Http:
             assert isinstance(config, HTTPConfig)
             return HTTPProducer(config)
 
+        if config.type == ProducerTypes.Local:
+            assert isinstance(config, LocalConfig)
+            return LocalProducer(config)
+
         assert isinstance(config, KafkaConfig)
         return KafkaProducer(config)
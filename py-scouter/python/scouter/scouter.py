@@ -1,6 +1,14 @@
+import asyncio
+import queue
+import random
+import tempfile
+import threading
+import time
+from contextlib import contextmanager
 from enum import Enum
 from functools import cached_property
-from typing import Any, Dict, List, Optional, Tuple, Union
+from pathlib import Path
+from typing import Any, Dict, Generator, Iterable, Iterator, List, Optional, Set, Tuple, TypeVar, Union, cast
 
 import numpy as np
 import pandas as pd
@@ -9,8 +17,11 @@ from numpy.typing import NDArray
 from scouter.integrations.base import BaseProducer
 from scouter.integrations.http import HTTPConfig
 from scouter.integrations.kafka import KafkaConfig
+from scouter.integrations.local import LocalConfig
 from scouter.integrations.producer import DriftRecordProducer
+from scouter.utils.expressions import FeatureExpression, compute_features
 from scouter.utils.logger import ScouterLogger
+from scouter.utils.types import SamplingStrategy
 
 from ._scouter import (  # pylint: disable=no-name-in-module
     AlertRule,
@@ -19,6 +30,7 @@ from ._scouter import (  # pylint: disable=no-name-in-module
     DriftConfig,
     DriftMap,
     DriftProfile,
+    DriftScore,
     DriftServerRecord,
     FeatureAlerts,
     ScouterDrifter,
@@ -29,6 +41,11 @@ logger = ScouterLogger.get_logger()
 
 CommonCrons = CommonCron()  # type: ignore
 
+# pyarrow Table, RecordBatch, RecordBatchReader or Dataset
+ArrowData = Any
+ScouterData = Union[pd.DataFrame, pl.DataFrame, pl.LazyFrame, NDArray, ArrowData]
+PolarsFrame = TypeVar("PolarsFrame", pl.DataFrame, pl.LazyFrame)
+
 
 class DataType(str, Enum):
     FLOAT32 = "float32"
@@ -37,6 +54,8 @@ class DataType(str, Enum):
     INT16 = "int16"
     INT32 = "int32"
     INT64 = "int64"
+    BOOL = "bool"
+    DATETIME = "datetime64"
 
     @staticmethod
     def str_to_bits(dtype: str) -> str:
@@ -47,33 +66,225 @@ class DataType(str, Enum):
         return bits[dtype]
 
 
+def _is_arrow_data(data: Any) -> bool:
+    """Checks whether data is a pyarrow object without requiring pyarrow to be installed."""
+    return type(data).__module__.split(".")[0] == "pyarrow"
+
+
+def _iter_arrow_batches(data: ArrowData) -> Iterator[Any]:
+    """Yields record batches from a pyarrow Table, RecordBatch, RecordBatchReader or Dataset."""
+    import pyarrow as pa
+
+    if isinstance(data, pa.RecordBatch):
+        yield data
+    elif isinstance(data, (pa.Table, pa.RecordBatchReader)):
+        yield from data
+    elif hasattr(data, "to_batches"):
+        # pyarrow.dataset.Dataset
+        yield from data.to_batches()
+    else:
+        raise ValueError(f"Unsupported arrow data type: {type(data)}")
+
+
+def _arrow_num_rows(data: ArrowData) -> Optional[int]:
+    """Number of rows of arrow data, or None for record batch readers."""
+    import pyarrow as pa
+
+    if isinstance(data, (pa.Table, pa.RecordBatch)):
+        return data.num_rows
+    if hasattr(data, "count_rows"):
+        # pyarrow.dataset.Dataset
+        return data.count_rows()
+    return None
+
+
+def _arrow_column_to_array(column: Any) -> NDArray:
+    """Converts an arrow column to numpy. Boolean columns are cast to 0/1 and date/timestamp
+    columns to (fractional) seconds since epoch, with nulls as NaN."""
+    import pyarrow as pa
+
+    if pa.types.is_boolean(column.type):
+        return column.cast(pa.float32()).to_numpy(zero_copy_only=False)
+    if pa.types.is_date(column.type):
+        column = column.cast(pa.timestamp("s"))
+    if pa.types.is_timestamp(column.type):
+        return _datetime_to_seconds(column.to_numpy(zero_copy_only=False))
+    return column.to_numpy(zero_copy_only=False)
+
+
+def _arrow_batch_to_array(batch: Any) -> NDArray:
+    return np.column_stack([_arrow_column_to_array(column) for column in batch.columns])
+
+
+def _cast_polars_columns(data: PolarsFrame) -> PolarsFrame:
+    """Casts boolean columns to 0/1 and date/datetime columns to (fractional) seconds since epoch."""
+    return data.with_columns(
+        pl.col(pl.Boolean).cast(pl.Float32),
+        pl.col(pl.Date, pl.Datetime).dt.epoch(time_unit="us").cast(pl.Float64) / 1_000_000,
+    )
+
+
+def _cast_pandas_columns(data: pd.DataFrame) -> pd.DataFrame:
+    """Casts boolean columns to 0/1 and datetime columns to (fractional) seconds since epoch.
+    Missing values of nullable boolean and datetime columns become NaN."""
+    bool_columns = data.select_dtypes(include=["bool", "boolean"]).columns
+    datetime_columns = data.select_dtypes(include=["datetime", "datetimetz"]).columns
+
+    if bool_columns.empty and datetime_columns.empty:
+        return data
+
+    data = data.copy()
+    for column in bool_columns:
+        data[column] = data[column].astype("float32")
+    for column in datetime_columns:
+        # subtracting the epoch works for any datetime resolution and timezone
+        epoch = pd.Timestamp(0, tz=data[column].dt.tz)
+        data[column] = (data[column] - epoch) / pd.Timedelta(seconds=1)
+
+    return data
+
+
+def _datetime_to_seconds(array: NDArray) -> NDArray:
+    """Converts a numpy datetime array of any resolution to float64 seconds since epoch."""
+    return (array - np.datetime64(0, "s")) / np.timedelta64(1, "s")
+
+
+def _column_kinds(data: ScouterData) -> Tuple[List[int], List[int]]:
+    """Returns the positions of boolean and date/datetime columns in the data."""
+    if isinstance(data, (pl.DataFrame, pl.LazyFrame)):
+        dtypes = list(data.schema.values())
+        return (
+            [i for i, dtype in enumerate(dtypes) if dtype == pl.Boolean],
+            [i for i, dtype in enumerate(dtypes) if dtype in (pl.Date, pl.Datetime)],
+        )
+
+    if isinstance(data, pd.DataFrame):
+        return (
+            [i for i, dtype in enumerate(data.dtypes) if pd.api.types.is_bool_dtype(dtype)],
+            [i for i, dtype in enumerate(data.dtypes) if pd.api.types.is_datetime64_any_dtype(dtype)],
+        )
+
+    if _is_arrow_data(data):
+        import pyarrow as pa
+
+        types = [field.type for field in data.schema]
+        return (
+            [i for i, dtype in enumerate(types) if pa.types.is_boolean(dtype)],
+            [i for i, dtype in enumerate(types) if pa.types.is_timestamp(dtype) or pa.types.is_date(dtype)],
+        )
+
+    columns = list(range(data.shape[1]))
+    if data.dtype == np.bool_:
+        return columns, []
+    if np.issubdtype(data.dtype, np.datetime64):
+        return [], columns
+    return [], []
+
+
 class ScouterBase:
-    def _convert_data_to_array(self, data: Union[pd.DataFrame, pl.DataFrame, NDArray]) -> NDArray:
+    def _convert_data_to_array(self, data: ScouterData) -> NDArray:
+        if isinstance(data, pl.LazyFrame):
+            return self._convert_lazy_to_array(data)
         if isinstance(data, pl.DataFrame):
-            return data.to_numpy()
+            return _cast_polars_columns(data).to_numpy()
         if isinstance(data, pd.DataFrame):
-            return data.to_numpy()
+            return _cast_pandas_columns(data).to_numpy()
+        if _is_arrow_data(data):
+            return self._convert_arrow_to_array(data)
         return data
 
+    def _convert_lazy_to_array(self, data: pl.LazyFrame, slice_size: int = 100_000) -> NDArray:
+        """Converts a polars LazyFrame to a numpy array without collecting it into a dataframe.
+
+        The query is run once with the streaming engine and written to a temporary arrow IPC
+        file, which is memory mapped and copied into a preallocated array one slice of rows
+        at a time. Peak memory is about the dataset size plus one slice. Queries the streaming
+        engine can not sink fall back to collecting the dataframe, which briefly holds about
+        twice the dataset size.
+        """
+        data = _cast_polars_columns(data)
+
+        with tempfile.TemporaryDirectory() as directory:
+            path = Path(directory) / "data.arrow"
+
+            try:
+                data.sink_ipc(path)
+            except (pl.exceptions.InvalidOperationError, pl.exceptions.ComputeError) as exc:
+                logger.warning(f"LazyFrame can not be streamed, collecting it in memory instead: {exc}")
+                return data.collect(streaming=True).to_numpy()
+
+            frame = pl.read_ipc(path, memory_map=True)
+            if frame.height == 0:
+                return frame.to_numpy()
+
+            array: Optional[NDArray] = None
+            for offset in range(0, frame.height, slice_size):
+                values = frame.slice(offset, slice_size).to_numpy()
+                if array is None:
+                    array = np.empty((frame.height, values.shape[1]), dtype=values.dtype)
+                array[offset : offset + values.shape[0]] = values
+
+            # release the memory map before the temporary file is removed
+            del frame
+
+        return cast(NDArray, array)
+
+    def _convert_arrow_to_array(self, data: ArrowData) -> NDArray:
+        """Converts arrow data to a numpy array one record batch at a time, so readers and
+        datasets are never read into a single arrow table.
+
+        The profilers need the whole dataset, so the result is a single numpy array.
+        When the number of rows is known up front (tables, record batches and datasets),
+        batches are copied into a preallocated array and peak memory is about the dataset
+        size plus one batch. Record batch readers are concatenated at the end, which briefly
+        holds about twice the dataset size.
+        """
+        num_rows = _arrow_num_rows(data)
+        batches = (batch for batch in _iter_arrow_batches(data) if batch.num_rows > 0)
+
+        if num_rows is None:
+            arrays = [_arrow_batch_to_array(batch) for batch in batches]
+
+            if not arrays:
+                raise ValueError("Arrow data does not contain any rows")
+
+            return np.concatenate(arrays, axis=0)
+
+        array: Optional[NDArray] = None
+        offset = 0
+        for batch in batches:
+            values = _arrow_batch_to_array(batch)
+            if array is None:
+                array = np.empty((num_rows, values.shape[1]), dtype=values.dtype)
+            array[offset : offset + batch.num_rows] = values
+            offset += batch.num_rows
+
+        if array is None:
+            raise ValueError("Arrow data does not contain any rows")
+
+        return array
+
     def _get_feature_names(
         self,
         features: Optional[List[str]],
-        data: Union[pd.DataFrame, pl.DataFrame, NDArray],
+        data: ScouterData,
     ) -> List[str]:
         if features is not None:
             return features
 
-        if isinstance(data, pl.DataFrame):
+        if isinstance(data, (pl.DataFrame, pl.LazyFrame)):
             return data.columns
         if isinstance(data, pd.DataFrame):
             columns = list(data.columns)
             return [str(i) for i in columns]
+        if _is_arrow_data(data):
+            return list(data.schema.names)
         return [f"feature_{i}" for i in range(data.shape[1])]
 
     def _preprocess(
         self,
         features: Optional[List[str]],
-        data: Union[pl.DataFrame, pd.DataFrame, NDArray],
+        data: ScouterData,
     ) -> Tuple[NDArray, List[str], str]:
         try:
             array = self._convert_data_to_array(data)
@@ -92,11 +303,52 @@ class ScouterBase:
 
                 return array, features, DataType.str_to_bits("float32")
 
+            if dtype == DataType.BOOL.value:
+                logger.warning("Converting boolean array to float32. Feature means represent the rate of true values.")
+                array = array.astype("float32")
+
+                return array, features, DataType.str_to_bits("float32")
+
+            if dtype.startswith(DataType.DATETIME.value):
+                logger.warning("Converting datetime array to float64 seconds since epoch.")
+                array = _datetime_to_seconds(array)
+
+                return array, features, DataType.str_to_bits("float64")
+
             return array, features, DataType.str_to_bits(dtype)
 
         except KeyError as exc:
             raise ValueError(f"Unsupported data type: {dtype}") from exc
 
+    def _add_computed_features(
+        self,
+        array: NDArray,
+        features: List[str],
+        computed_features: Dict[str, str],
+        feature_aliases: Optional[Dict[str, str]] = None,
+    ) -> Tuple[NDArray, List[str]]:
+        """Appends computed feature columns to the array. Computed features that are
+        already present in the data are not recomputed."""
+        aliases = feature_aliases or {}
+        resolved = [aliases.get(feature, feature) for feature in features]
+        expressions = {
+            feature: FeatureExpression(expression)
+            for feature, expression in computed_features.items()
+            if feature not in resolved
+        }
+
+        if not expressions:
+            return array, features
+
+        columns = {feature: array[:, i] for i, feature in enumerate(resolved)}
+        computed = compute_features(expressions, columns)
+
+        array = np.column_stack(
+            [array] + [np.broadcast_to(values, array.shape[0]).astype(array.dtype) for values in computed.values()]
+        )
+
+        return array, features + list(computed)
+
 
 class Profiler(ScouterBase):
     def __init__(self) -> None:
@@ -106,9 +358,10 @@ class Profiler(ScouterBase):
 
     def create_data_profile(
         self,
-        data: Union[pl.DataFrame, pd.DataFrame, NDArray],
+        data: ScouterData,
         features: Optional[List[str]] = None,
         bin_size: int = 20,
+        approx_distinct: bool = False,
     ) -> DataProfile:
         """Create a data profile from data.
 
@@ -118,23 +371,39 @@ class Profiler(ScouterBase):
                 automatically generated.
             data:
                 Data to create a data profile from. Data can be a numpy array,
-                a polars dataframe or pandas dataframe. Data is expected to not contain
-                any missing values, NaNs or infinities. These values must be removed or imputed.
-                If NaNs or infinities are present, the data profile will not be created.
+                a polars dataframe or lazyframe, a pandas dataframe or a pyarrow table,
+                record batch reader or dataset. Apart from boolean and datetime columns,
+                data is expected to not contain any missing values, NaNs or infinities.
+                These values must be removed or imputed, otherwise the data profile
+                will not be created.
             bin_size:
                 Optional bin size for histograms. Defaults to 20 bins.
+            approx_distinct:
+                Whether to estimate distinct counts and most frequent values with
+                HyperLogLog and space-saving sketches instead of exact counting.
+                Recommended for large, high-cardinality datasets. Defaults to False.
+
+        Boolean columns are profiled as 0/1, so their mean is the rate of true values,
+        and also get true/false/null counts. Date and datetime columns are profiled as seconds
+        since epoch and also get weekday and hour of day (UTC) distributions. Boolean and
+        datetime columns may contain missing values (nulls or NaT), which are reported as
+        `missing_count` and `missing_rate` and left out of the other stats.
 
         Returns:
             Monitoring profile
         """
         try:
             logger.info("Creating data profile.")
+            boolean_columns, datetime_columns = _column_kinds(data)
             array, features, bits = self._preprocess(features, data)
 
             profile = getattr(self._profiler, f"create_data_profile_f{bits}")(
                 features=features,
                 array=array,
                 bin_size=bin_size,
+                approx_distinct=approx_distinct,
+                boolean_features=[features[i] for i in boolean_columns],
+                datetime_features=[features[i] for i in datetime_columns],
             )
 
             assert isinstance(profile, DataProfile), f"Expected DataProfile, got {type(profile)}"
@@ -156,9 +425,11 @@ class Drifter(ScouterBase):
 
     def create_drift_profile(
         self,
-        data: Union[pl.DataFrame, pd.DataFrame, NDArray],
+        data: ScouterData,
         monitor_config: DriftConfig,
         features: Optional[List[str]] = None,
+        feature_importance: Optional[Dict[str, float]] = None,
+        computed_features: Optional[Dict[str, str]] = None,
     ) -> DriftProfile:
         """Create a drift profile from data to use for monitoring.
 
@@ -168,11 +439,21 @@ class Drifter(ScouterBase):
                 automatically generated.
             data:
                 Data to create a monitoring profile from. Data can be a numpy array,
-                a polars dataframe or pandas dataframe. Data is expected to not contain
+                a polars dataframe or lazyframe, a pandas dataframe or a pyarrow table,
+                record batch reader or dataset. Data is expected to not contain
                 any missing values, NaNs or infinities. These values must be removed or imputed.
                 If NaNs or infinities are present, the monitoring profile will not be created.
             monitor_config:
                 Configuration for the monitoring profile.
+            feature_importance:
+                Optional dictionary of feature names and their importance. Used to weight
+                features when computing a model-level drift score. Features that are not
+                included are ignored in the score.
+            computed_features:
+                Optional dictionary of computed feature names and the expressions used to
+                compute them from other features, e.g. {"income_per_age": "income / age"}.
+                Computed features are profiled like any other feature and are computed
+                automatically when computing drift and inserting into a MonitorQueue.
 
         Returns:
             Monitoring profile
@@ -181,6 +462,9 @@ class Drifter(ScouterBase):
             logger.info("Creating drift profile.")
             array, features, bits = self._preprocess(features, data)
 
+            if computed_features:
+                array, features = self._add_computed_features(array, features, computed_features)
+
             profile = getattr(self._drifter, f"create_drift_profile_f{bits}")(
                 features=features,
                 array=array,
@@ -188,6 +472,13 @@ class Drifter(ScouterBase):
             )
 
             assert isinstance(profile, DriftProfile), f"Expected DriftProfile, got {type(profile)}"
+
+            if feature_importance is not None:
+                profile.feature_weights = feature_importance
+
+            if computed_features:
+                profile.computed_features = computed_features
+
             return profile
 
         except Exception as exc:  # type: ignore
@@ -196,7 +487,7 @@ class Drifter(ScouterBase):
 
     def compute_drift(
         self,
-        data: Union[pl.DataFrame, pd.DataFrame, NDArray],
+        data: ScouterData,
         drift_profile: DriftProfile,
         features: Optional[List[str]] = None,
     ) -> DriftMap:
@@ -208,8 +499,10 @@ class Drifter(ScouterBase):
                 automatically generated. Names must match the feature names in the monitoring profile.
             data:
                 Data to compute drift from. Data can be a numpy array,
-                a polars dataframe or pandas dataframe. Data is expected to not contain
-                any missing values, NaNs or infinities.
+                a polars dataframe or lazyframe, a pandas dataframe or a pyarrow table,
+                record batch reader or dataset. Data is expected to not contain
+                any missing values, NaNs or infinities. Computed features of the
+                monitoring profile are computed from the data if not present.
             drift_profile:
                 Monitoring profile containing feature drift profiles.
 
@@ -218,6 +511,14 @@ class Drifter(ScouterBase):
             logger.info("Computing drift")
             array, features, bits = self._preprocess(features, data)
 
+            if drift_profile.computed_features:
+                array, features = self._add_computed_features(
+                    array,
+                    features,
+                    drift_profile.computed_features,
+                    drift_profile.feature_aliases,
+                )
+
             drift_map = getattr(self._drifter, f"compute_drift_f{bits}")(
                 features=features,
                 drift_array=array,
@@ -232,6 +533,175 @@ class Drifter(ScouterBase):
             logger.error(f"Failed to compute drift: {exc}")
             raise ValueError(f"Failed to compute drift: {exc}") from exc
 
+    def compute_drift_score(self, drift_map: DriftMap, drift_profile: DriftProfile) -> DriftScore:
+        """Aggregate feature drift into a single model-level drift score between 0 and 1.
+        Features are weighted by the feature weights of the drift profile, or equally if
+        the profile has no feature weights.
+
+        Args:
+            drift_map:
+                Drift map computed from the drift profile.
+            drift_profile:
+                Monitoring profile containing feature drift profiles.
+
+        Returns:
+            Drift score with the average score and the score of each sample
+        """
+        try:
+            return self._drifter.compute_drift_score(drift_map, drift_profile)
+
+        except Exception as exc:
+            logger.error(f"Failed to compute drift score: {exc}")
+            raise ValueError(f"Failed to compute drift score: {exc}") from exc
+
+    def sample_batches(
+        self,
+        batches: Iterable[ArrowData],
+        drift_profile: DriftProfile,
+        features: Optional[List[str]] = None,
+        batches_per_chunk: int = 16,
+    ) -> Iterator[List[DriftServerRecord]]:
+        """Sample drift records from an iterable of arrow data (e.g. record batches produced
+        by Spark or Ray workers) for bulk insertion. Batches are sampled in parallel, one
+        chunk of batches at a time, so the full dataset is never held in memory.
+
+        Rows that do not fill a complete sample window at the end of a batch are carried
+        over into the next batch, so samples match sampling all rows at once and only the
+        last sample can be smaller than the profile's sample size. Computed features of the
+        profile are added to each batch.
+
+        Args:
+            batches:
+                Iterable of pyarrow Tables, RecordBatches, RecordBatchReaders or Datasets.
+            drift_profile:
+                Monitoring profile containing feature drift profiles.
+            features:
+                Optional list of feature names. If not provided, the schema names
+                of the first batch are used.
+            batches_per_chunk:
+                Number of record batches to sample in parallel.
+
+        Returns:
+            Iterator of drift record lists, one list per chunk of batches.
+        """
+        chunk: List[NDArray] = []
+        batch_features: List[str] = []
+        sample_size = max(drift_profile.config.sample_size, 1)
+        remainder: Optional[NDArray] = None
+
+        for data in batches:
+            for batch in _iter_arrow_batches(data):
+                if features is None:
+                    features = list(batch.schema.names)
+
+                if batch.num_rows == 0:
+                    continue
+
+                array = self._convert_arrow_to_array(batch).astype("float64")
+                if drift_profile.computed_features:
+                    array, batch_features = self._add_computed_features(
+                        array,
+                        features,
+                        drift_profile.computed_features,
+                        drift_profile.feature_aliases,
+                    )
+                else:
+                    batch_features = features
+
+                if remainder is not None:
+                    array = np.concatenate([remainder, array])
+
+                complete = array.shape[0] - array.shape[0] % sample_size
+                remainder = array[complete:] if complete < array.shape[0] else None
+
+                if complete == 0:
+                    continue
+
+                chunk.append(array[:complete])
+
+                if len(chunk) >= batches_per_chunk:
+                    yield self._sample_chunk(chunk, batch_features, drift_profile)
+                    chunk = []
+
+        if remainder is not None:
+            chunk.append(remainder)
+
+        if chunk:
+            yield self._sample_chunk(chunk, batch_features, drift_profile)
+
+    def _sample_chunk(
+        self,
+        chunk: List[NDArray],
+        features: List[str],
+        drift_profile: DriftProfile,
+    ) -> List[DriftServerRecord]:
+        try:
+            return self._drifter.sample_batches_f64(features, chunk, drift_profile)
+
+        except Exception as exc:
+            logger.error(f"Failed to sample batches: {exc}")
+            raise ValueError(f"Failed to sample batches: {exc}") from exc
+
+    def estimate_false_positive_rate(
+        self,
+        data: ScouterData,
+        drift_profile: DriftProfile,
+        features: Optional[List[str]] = None,
+        num_windows: int = 1000,
+        window_size: int = 10,
+        seed: Optional[int] = None,
+    ) -> Dict[str, float]:
+        """Estimate the expected false positive alert rate of a drift profile before going live.
+
+        Monitoring windows are bootstrapped from baseline data (typically the data the profile
+        was created from) by drawing samples of rows with replacement. Drift is computed for
+        each window and checked against the profile's alert rule. Since baseline data has no
+        drift, every alert is a false positive.
+
+        Args:
+            data:
+                Baseline data. Data can be a numpy array, a polars dataframe or lazyframe,
+                a pandas dataframe or a pyarrow table, record batch reader or dataset.
+            drift_profile:
+                Monitoring profile containing feature drift profiles.
+            features:
+                Optional list of feature names. If not provided, feature names will be
+                automatically generated. Names must match the feature names in the monitoring profile.
+            num_windows:
+                Number of bootstrapped monitoring windows. Defaults to 1000.
+            window_size:
+                Number of samples in each window, e.g. the number of samples collected
+                between scheduled runs. Defaults to 10.
+            seed:
+                Optional seed for reproducible estimates.
+
+        Returns:
+            Dictionary of feature names and the fraction of windows with at least one alert
+        """
+        try:
+            array, features, bits = self._preprocess(features, data)
+
+            if drift_profile.computed_features:
+                array, features = self._add_computed_features(
+                    array,
+                    features,
+                    drift_profile.computed_features,
+                    drift_profile.feature_aliases,
+                )
+
+            return getattr(self._drifter, f"estimate_false_positive_rate_f{bits}")(
+                features=features,
+                array=array,
+                drift_profile=drift_profile,
+                num_windows=num_windows,
+                window_size=window_size,
+                seed=seed,
+            )
+
+        except Exception as exc:  # type: ignore
+            logger.error(f"Failed to estimate false positive rate: {exc}")
+            raise ValueError(f"Failed to estimate false positive rate: {exc}") from exc
+
     def generate_alerts(
         self,
         drift_array: NDArray,
@@ -264,7 +734,11 @@ class MonitorQueue:
     def __init__(
         self,
         drift_profile: DriftProfile,
-        config: Union[KafkaConfig, HTTPConfig],
+        config: Union[KafkaConfig, HTTPConfig, LocalConfig, BaseProducer],
+        sample_rate: float = 1.0,
+        max_pending: int = 10_000,
+        sampling: Union[str, SamplingStrategy] = SamplingStrategy.Uniform,
+        seed: Optional[int] = None,
     ) -> None:
         """Instantiate a monitoring queue to monitor data drift.
 
@@ -274,21 +748,141 @@ class MonitorQueue:
             config:
                 Configuration for the monitoring producer. The configured producer
                 will be used to publish drift records to the monitoring server.
+                A BaseProducer instance can be passed to publish records with a
+                custom producer.
+            sample_rate:
+                Fraction of inserts to monitor, between 0 and 1. Defaults to 1.0
+                (monitor every insert).
+            max_pending:
+                Maximum number of inserts waiting to be processed when using `try_insert`.
+                Inserts beyond this limit are dropped.
+            sampling:
+                How inserts are sampled when `sample_rate` is below 1. `uniform` keeps each
+                insert independently with probability `sample_rate`. `reservoir` splits inserts
+                into windows of `sample_size / sample_rate` inserts and publishes a uniform
+                sample of `sample_size` inserts from each window. Defaults to `uniform`.
+            seed:
+                Optional seed for the random sampling of inserts.
+
+        `total_count` and `sampled_count` can be used to de-bias statistics computed from
+        the published samples. The counts are not attached to the published drift records,
+        whose schema is shared with the scouter server.
         """
+        if not 0.0 < sample_rate <= 1.0:
+            raise ValueError(f"sample_rate must be between 0 and 1, got {sample_rate}")
+
         self._monitor = ScouterDrifter()
         self._drift_profile = drift_profile
+        self._sample_rate = sample_rate
+        self._sampling = SamplingStrategy(sampling)
+        self._random = random.Random(seed)
+        self._batch_random = np.random.default_rng(seed)
+
+        # inserts seen in the current reservoir window
+        sample_size = drift_profile.config.sample_size
+        self._window_size = max(sample_size, round(sample_size / sample_rate))
+        self._window_count = 0
 
         self.feature_queue: Dict[str, List[float]] = {feature: [] for feature in self.feature_names}
         self._count = 0
+        self._total_count = 0
+        self._sampled_count = 0
+
+        # inserts from try_insert are processed by a background worker, which is started
+        # under its own lock so try_insert never waits on a publish holding the queue lock
+        self._lock = threading.RLock()
+        self._pending: "queue.Queue[Dict[Any, Any]]" = queue.Queue(maxsize=max_pending)
+        self._worker: Optional[threading.Thread] = None
+        self._worker_lock = threading.Lock()
+        self._closed = threading.Event()
+
+        # metrics are updated under their own lock so try_insert never waits on a publish
+        self._metrics_lock = threading.Lock()
+        self._dropped_count = 0
+        self._blocked_count = 0
 
         self._producer = self._get_producer(config)
 
+    @property
+    def total_count(self) -> int:
+        """Number of inserts received by the queue, including inserts that were not sampled."""
+        return self._total_count
+
+    @property
+    def sampled_count(self) -> int:
+        """Number of inserts that were sampled into the queue. With reservoir sampling,
+        inserts that replace an already sampled insert are not counted again."""
+        return self._sampled_count
+
+    @property
+    def dropped_count(self) -> int:
+        """Number of `try_insert` calls that were dropped because too many inserts were pending."""
+        with self._metrics_lock:
+            return self._dropped_count
+
+    @property
+    def blocked_count(self) -> int:
+        """Number of `insert` and `insert_batch` calls (including inserts processed for
+        `try_insert`) that had to wait for another insert or publish to finish."""
+        with self._metrics_lock:
+            return self._blocked_count
+
+    @property
+    def pending_count(self) -> int:
+        """Number of `try_insert` calls waiting to be processed."""
+        return self._pending.unfinished_tasks
+
+    @contextmanager
+    def _queue_lock(self) -> Generator[None, None, None]:
+        """Acquires the queue lock, counting inserts that had to wait for it."""
+        if not self._lock.acquire(blocking=False):
+            with self._metrics_lock:
+                self._blocked_count += 1
+            self._lock.acquire()
+
+        try:
+            yield
+        finally:
+            self._lock.release()
+
     @cached_property
     def feature_names(self) -> List[str]:
         """Feature names in the monitoring profile."""
         return list(self._drift_profile.features.keys())
 
-    def _get_producer(self, config: Union[KafkaConfig, HTTPConfig]) -> BaseProducer:
+    @cached_property
+    def _feature_aliases(self) -> Dict[str, str]:
+        """Alternative feature names mapped to features in the monitoring profile."""
+        return self._drift_profile.feature_aliases
+
+    @cached_property
+    def _computed_features(self) -> Dict[str, FeatureExpression]:
+        """Expressions of the computed features in the monitoring profile."""
+        return {
+            feature: FeatureExpression(expression)
+            for feature, expression in self._drift_profile.computed_features.items()
+        }
+
+    @cached_property
+    def _computed_inputs(self) -> Set[str]:
+        """Raw inputs of computed features that are not features in the monitoring profile."""
+        inputs: Set[str] = set()
+        for expression in self._computed_features.values():
+            inputs |= expression.features
+        return inputs - set(self.feature_names)
+
+    def _resolve_features(self, data: Dict[Any, Any]) -> Dict[Any, Any]:
+        """Resolves feature aliases and adds computed features that are not present. Raw
+        inputs of computed features (e.g. strings for `len`) are dropped once computed."""
+        data = {self._feature_aliases.get(feature, feature): value for feature, value in data.items()}
+
+        expressions = {feature: expr for feature, expr in self._computed_features.items() if feature not in data}
+        if expressions:
+            data.update(compute_features(expressions, data))
+
+        return {feature: value for feature, value in data.items() if feature not in self._computed_inputs}
+
+    def _get_producer(self, config: Union[KafkaConfig, HTTPConfig, LocalConfig, BaseProducer]) -> BaseProducer:
         """Get the producer based on the configuration."""
         return DriftRecordProducer.get_producer(config)
 
@@ -298,41 +892,325 @@ class MonitorQueue:
         Args:
             data:
                 Dictionary of feature values to insert into the monitoring queue.
+                Feature aliases defined in the drift profile are resolved to their
+                profile feature names, and computed features are computed from the
+                inserted values.
 
         Returns:
             List of drift records if the monitoring queue has enough data to compute
         """
-        for feature, value in data.items():
-            self.feature_queue[feature].append(value)
+        with self._queue_lock():
+            self._total_count += 1
+
+            if self._sampling == SamplingStrategy.Reservoir:
+                return self._insert_reservoir(data)
+
+            if self._sample_rate < 1.0 and self._random.random() >= self._sample_rate:
+                return None
+
+            self._sampled_count += 1
+
+            for feature, value in self._resolve_features(data).items():
+                self.feature_queue[feature].append(value)
+
+            self._count += 1
+
+            if self._count >= self._drift_profile.config.sample_size:
+                return self.publish()
+
+            return None
+
+    def _insert_reservoir(self, data: Dict[Any, Any]) -> Optional[List[DriftServerRecord]]:
+        """Adds an insert to the reservoir of the current window (algorithm R) and publishes
+        the reservoir once the window is complete."""
+        sample_size = self._drift_profile.config.sample_size
+        self._window_count += 1
+
+        if self._count < sample_size:
+            for feature, value in self._resolve_features(data).items():
+                self.feature_queue[feature].append(value)
+
+            self._count += 1
+            self._sampled_count += 1
+
+        else:
+            position = self._random.randrange(self._window_count)
 
-        self._count += 1
+            if position < sample_size:
+                for feature, value in self._resolve_features(data).items():
+                    self.feature_queue[feature][position] = value
 
-        if self._count >= self._drift_profile.config.sample_size:
+        if self._window_count >= self._window_size:
             return self.publish()
 
         return None
 
+    def insert_batch(self, data: Union[Dict[str, Any], ScouterData]) -> List[DriftServerRecord]:
+        """Insert many rows into the monitoring queue at once. Every complete sample
+        of rows is published in a single call, and leftover rows stay in the queue.
+
+        Args:
+            data:
+                Columnar data to insert. Can be a dictionary of feature names and
+                array-like values, a polars dataframe or lazyframe, a pandas dataframe
+                or a pyarrow table, record batch or record batch reader. Feature aliases
+                defined in the drift profile are resolved to their profile feature names,
+                and computed features are computed from the inserted columns.
+
+        Returns:
+            List of drift records published from complete samples
+        """
+        columns = self._get_batch_columns(data)
+        num_rows = len(next(iter(columns.values()))) if columns else 0
+
+        with self._queue_lock():
+            self._total_count += num_rows
+
+            if self._sampling == SamplingStrategy.Reservoir:
+                drift_records: List[DriftServerRecord] = []
+
+                for row in range(num_rows):
+                    published = self._insert_reservoir({feature: values[row] for feature, values in columns.items()})
+                    drift_records.extend(published or [])
+
+                return drift_records
+
+            if self._sample_rate < 1.0:
+                mask = self._batch_random.random(num_rows) < self._sample_rate
+                columns = {feature: values[mask] for feature, values in columns.items()}
+                num_rows = int(mask.sum())
+
+            self._sampled_count += num_rows
+
+            for feature, values in self._resolve_features(columns).items():
+                self.feature_queue[feature].extend(np.broadcast_to(values, num_rows).tolist())
+
+            self._count += num_rows
+
+            sample_size = self._drift_profile.config.sample_size
+            num_complete = self._count - self._count % sample_size
+
+            if num_complete == 0:
+                return []
+
+            try:
+                array = np.array(
+                    [self.feature_queue[feature][:num_complete] for feature in self.feature_names],
+                    dtype=np.float64,
+                ).T
+
+                drift_records = self._monitor.sample_data_f64(self.feature_names, array, self._drift_profile)
+
+                for record in drift_records:
+                    self._producer.publish(record)
+
+                # keep rows that do not fill a complete sample
+                self.feature_queue = {
+                    feature: values[num_complete:] for feature, values in self.feature_queue.items()
+                }
+                self._count -= num_complete
+
+                return drift_records
+
+            except Exception as exc:
+                logger.error(f"Failed to compute drift: {exc}")
+                raise ValueError(f"Failed to compute drift: {exc}") from exc
+
+    def _get_batch_columns(self, data: Union[Dict[str, Any], ScouterData]) -> Dict[str, NDArray]:
+        """Convert columnar data to a dictionary of feature names and numpy arrays."""
+        if isinstance(data, dict):
+            return {str(feature): np.asarray(values) for feature, values in data.items()}
+
+        if isinstance(data, pl.LazyFrame):
+            # inserted rows are queued in memory anyway, and numeric columns without nulls
+            # are converted to numpy without copying
+            data = data.collect(streaming=True)
+
+        if isinstance(data, pl.DataFrame):
+            data = _cast_polars_columns(data)
+            return {column: data[column].to_numpy() for column in data.columns}
+
+        if isinstance(data, pd.DataFrame):
+            data = _cast_pandas_columns(data)
+            return {str(column): data[column].to_numpy() for column in data.columns}
+
+        if _is_arrow_data(data):
+            batches = [batch for batch in _iter_arrow_batches(data) if batch.num_rows > 0]
+            if not batches:
+                return {}
+
+            return {
+                name: np.concatenate([_arrow_column_to_array(batch.column(i)) for batch in batches])
+                for i, name in enumerate(batches[0].schema.names)
+            }
+
+        raise ValueError(f"Unsupported data type for batch insert: {type(data)}")
+
+    def try_insert(self, data: Dict[Any, Any]) -> bool:
+        """Insert data into the monitoring queue without blocking. The data is processed
+        by a background worker, which publishes drift records once the queue has enough data.
+
+        Args:
+            data:
+                Dictionary of feature values to insert into the monitoring queue.
+
+        Returns:
+            True if the data was accepted, False if it was dropped because `max_pending`
+            inserts are already waiting to be processed.
+
+        Raises:
+            RuntimeError: When the queue has been closed.
+        """
+        if self._closed.is_set():
+            raise RuntimeError("Cannot insert into a closed MonitorQueue")
+
+        self._start_worker()
+
+        try:
+            self._pending.put_nowait(data)
+            return True
+
+        except queue.Full:
+            with self._metrics_lock:
+                self._dropped_count += 1
+            return False
+
+    def _start_worker(self) -> None:
+        """Start the background worker that processes pending inserts."""
+        if self._worker is not None:
+            return
+
+        with self._worker_lock:
+            if self._worker is None:
+                self._worker = threading.Thread(target=self._process_pending, daemon=True)
+                self._worker.start()
+
+    def _process_pending(self) -> None:
+        """Process pending inserts until the queue is closed and no inserts are pending."""
+        while not (self._closed.is_set() and self._pending.empty()):
+            try:
+                data = self._pending.get(timeout=0.1)
+            except queue.Empty:
+                continue
+
+            try:
+                self.insert(data)
+            except Exception as exc:  # pylint: disable=broad-except
+                logger.error(f"Failed to process pending insert: {exc}")
+            finally:
+                self._pending.task_done()
+
+    def _wait_pending(self, deadline: Optional[float]) -> None:
+        """Wait until all pending inserts are processed or the deadline passes."""
+        with self._pending.all_tasks_done:
+            while self._pending.unfinished_tasks:
+                if deadline is None:
+                    self._pending.all_tasks_done.wait()
+                    continue
+
+                remaining = deadline - time.monotonic()
+                if remaining <= 0:
+                    raise TimeoutError(
+                        f"Timed out waiting for {self._pending.unfinished_tasks} pending inserts to be processed"
+                    )
+
+                self._pending.all_tasks_done.wait(remaining)
+
+    def flush(self, timeout: Optional[float] = None) -> List[DriftServerRecord]:
+        """Wait for pending inserts, publish any data left in the queue and flush the producer.
+
+        Args:
+            timeout:
+                Optional timeout in seconds for the whole flush. The time left after
+                processing pending inserts is passed to the producer flush.
+
+        Raises:
+            TimeoutError: When pending inserts are not processed within the timeout.
+                Data left in the queue is not published in that case.
+
+        Returns:
+            List of drift records published from data left in the queue
+        """
+        deadline = None if timeout is None else time.monotonic() + timeout
+        self._wait_pending(deadline)
+
+        with self._lock:
+            drift_records = self.publish() if self._count > 0 else []
+
+        remaining = None if deadline is None else max(deadline - time.monotonic(), 0.0)
+        self._producer.flush(remaining)
+
+        return drift_records
+
+    def close(self, timeout: Optional[float] = None) -> List[DriftServerRecord]:
+        """Stop the background worker once pending inserts are processed, then publish any
+        data left in the queue and flush the producer. `try_insert` raises after the queue
+        is closed, while `insert` and `insert_batch` can still be used.
+
+        Args:
+            timeout:
+                Optional timeout in seconds for the whole close.
+
+        Raises:
+            TimeoutError: When pending inserts are not processed within the timeout.
+                The worker keeps processing them in the background in that case.
+
+        Returns:
+            List of drift records published from data left in the queue
+        """
+        deadline = None if timeout is None else time.monotonic() + timeout
+        self._closed.set()
+
+        with self._worker_lock:
+            worker = self._worker
+
+        if worker is not None:
+            worker.join(None if deadline is None else max(deadline - time.monotonic(), 0.0))
+
+            if worker.is_alive():
+                raise TimeoutError(
+                    f"Timed out waiting for {self._pending.unfinished_tasks} pending inserts to be processed"
+                )
+
+        remaining = None if deadline is None else max(deadline - time.monotonic(), 0.0)
+        return self.flush(remaining)
+
+    async def flush_async(self, timeout: Optional[float] = None) -> List[DriftServerRecord]:
+        """Awaitable version of `flush` that runs the flush in the default executor.
+
+        Args:
+            timeout:
+                Optional timeout in seconds for the whole flush.
+
+        Returns:
+            List of drift records published from data left in the queue
+        """
+        loop = asyncio.get_running_loop()
+        return await loop.run_in_executor(None, self.flush, timeout)
+
     def _clear_queue(self) -> None:
         """Clear the monitoring queue."""
         self.feature_queue = {feature: [] for feature in self.feature_names}
         self._count = 0
+        self._window_count = 0
 
     def publish(self) -> List[DriftServerRecord]:
         """Publish drift records to the monitoring server."""
         try:
-            # create array from items
-            data = list(self.feature_queue.values())
-            array = np.array(data, dtype=np.float64).T
+            with self._lock:
+                # create array from items
+                data = list(self.feature_queue.values())
+                array = np.array(data, dtype=np.float64).T
 
-            drift_records = self._monitor.sample_data_f64(self.feature_names, array, self._drift_profile)
+                drift_records = self._monitor.sample_data_f64(self.feature_names, array, self._drift_profile)
 
-            for record in drift_records:
-                self._producer.publish(record)
+                for record in drift_records:
+                    self._producer.publish(record)
 
-            # clear items
-            self._clear_queue()
+                # clear items
+                self._clear_queue()
 
-            return drift_records
+                return drift_records
 
         except Exception as exc:
             logger.error(f"Failed to compute drift: {exc}")
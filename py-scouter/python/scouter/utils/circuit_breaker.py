@@ -0,0 +1,89 @@
+import threading
+import time
+from enum import Enum
+from typing import Optional
+
+
+class CircuitState(str, Enum):
+    Closed = "closed"
+    Open = "open"
+    HalfOpen = "half_open"
+
+
+class CircuitBreakerOpenError(ValueError):
+    """Raised when a request is short-circuited because the circuit is open."""
+
+    def __init__(self, message: str, request_id: Optional[str] = None) -> None:
+        super().__init__(message)
+        self.request_id = request_id
+
+
+class CircuitBreaker:
+    def __init__(self, failure_threshold: int = 5, reset_timeout: float = 30.0) -> None:
+        """Circuit breaker that short-circuits requests while a server is unavailable.
+
+        After `failure_threshold` consecutive failures the circuit opens and requests are
+        rejected. Once `reset_timeout` seconds have passed, a single trial request is allowed
+        (half open). A success closes the circuit, a failure opens it again.
+
+        Args:
+            failure_threshold:
+                Number of consecutive failures before the circuit opens.
+            reset_timeout:
+                Seconds to wait before allowing a trial request.
+        """
+        self.failure_threshold = failure_threshold
+        self.reset_timeout = reset_timeout
+        self.failure_count = 0
+        self._opened_at = 0.0
+        self._state = CircuitState.Closed
+        self._trial_in_flight = False
+        self._lock = threading.Lock()
+
+    @property
+    def state(self) -> CircuitState:
+        """Current state of the circuit."""
+        with self._lock:
+            return self._current_state()
+
+    def _current_state(self) -> CircuitState:
+        if self._state == CircuitState.Open and time.monotonic() - self._opened_at >= self.reset_timeout:
+            self._state = CircuitState.HalfOpen
+            self._trial_in_flight = False
+        return self._state
+
+    def allow_request(self) -> bool:
+        """Whether a request may be attempted. While half open, only the first caller is
+        allowed through until its outcome is recorded."""
+        with self._lock:
+            state = self._current_state()
+
+            if state == CircuitState.Open:
+                return False
+
+            if state == CircuitState.HalfOpen:
+                if self._trial_in_flight:
+                    return False
+                self._trial_in_flight = True
+
+            return True
+
+    def record_success(self) -> None:
+        with self._lock:
+            self.failure_count = 0
+            self._state = CircuitState.Closed
+            self._trial_in_flight = False
+
+    def record_failure(self) -> None:
+        with self._lock:
+            self.failure_count += 1
+            self._trial_in_flight = False
+            if self._state == CircuitState.HalfOpen or self.failure_count >= self.failure_threshold:
+                self._state = CircuitState.Open
+                self._opened_at = time.monotonic()
+
+    def release(self) -> None:
+        """Ends an allowed request without recording an outcome, e.g. when it failed for a
+        reason that says nothing about the server's availability."""
+        with self._lock:
+            self._trial_in_flight = False
@@ -0,0 +1,139 @@
+import ast
+import operator
+from typing import Any, Callable, Dict, Mapping, Set
+
+import numpy as np
+
+
+def _power(base: Any, exponent: Any) -> Any:
+    # float powers overflow to inf instead of computing huge integers (e.g. 9 ** 9 ** 9)
+    return np.float_power(base, exponent)
+
+
+_BINARY_OPERATORS: Dict[type, Callable[[Any, Any], Any]] = {
+    ast.Add: operator.add,
+    ast.Sub: operator.sub,
+    ast.Mult: operator.mul,
+    ast.Div: operator.truediv,
+    ast.FloorDiv: operator.floordiv,
+    ast.Mod: operator.mod,
+    ast.Pow: _power,
+}
+
+_UNARY_OPERATORS: Dict[type, Callable[[Any], Any]] = {
+    ast.USub: operator.neg,
+    ast.UAdd: operator.pos,
+}
+
+
+def _length(value: Any) -> Any:
+    if isinstance(value, np.ndarray):
+        return np.vectorize(len, otypes=[np.float64])(value)
+    return len(value)
+
+
+_FUNCTIONS: Dict[str, Callable[..., Any]] = {
+    "abs": np.abs,
+    "exp": np.exp,
+    "len": _length,
+    "log": np.log,
+    "log1p": np.log1p,
+    "max": np.maximum,
+    "min": np.minimum,
+    "sqrt": np.sqrt,
+}
+
+
+class FeatureExpression:
+    def __init__(self, expression: str) -> None:
+        """Arithmetic expression that computes a feature from other feature values.
+
+        Expressions reference features by name and support +, -, *, /, //, %, **,
+        numeric constants and the functions abs, exp, len, log, log1p, max, min and sqrt.
+        For example: "log1p(income) / (age + 1)".
+
+        Args:
+            expression:
+                Expression to compute the feature.
+        """
+        self.expression = expression
+
+        try:
+            self._tree = ast.parse(expression, mode="eval")
+        except SyntaxError as exc:
+            raise ValueError(f"Invalid feature expression {expression!r}: {exc}") from exc
+
+        self._validate(self._tree.body)
+
+    def _validate(self, node: ast.AST) -> None:
+        if isinstance(node, ast.BinOp) and type(node.op) in _BINARY_OPERATORS:
+            self._validate(node.left)
+            self._validate(node.right)
+        elif isinstance(node, ast.UnaryOp) and type(node.op) in _UNARY_OPERATORS:
+            self._validate(node.operand)
+        elif isinstance(node, ast.Call) and isinstance(node.func, ast.Name) and node.func.id in _FUNCTIONS:
+            if node.keywords:
+                raise ValueError(f"Keyword arguments are not supported in feature expression {self.expression!r}")
+            for arg in node.args:
+                self._validate(arg)
+        elif isinstance(node, ast.Name):
+            return
+        elif isinstance(node, ast.Constant) and isinstance(node.value, (int, float)):
+            return
+        else:
+            raise ValueError(f"Unsupported syntax {ast.dump(node)!r} in feature expression {self.expression!r}")
+
+    @property
+    def features(self) -> Set[str]:
+        """Names of the features referenced by the expression."""
+        functions = {id(node.func) for node in ast.walk(self._tree) if isinstance(node, ast.Call)}
+        return {
+            node.id for node in ast.walk(self._tree) if isinstance(node, ast.Name) and id(node) not in functions
+        }
+
+    def evaluate(self, values: Mapping[str, Any]) -> Any:
+        """Evaluates the expression. Values can be scalars or numpy arrays.
+
+        Args:
+            values:
+                Mapping of feature names to values.
+
+        Returns:
+            Computed feature value(s)
+        """
+        missing = self.features - set(values)
+        if missing:
+            raise ValueError(f"Missing features {sorted(missing)} for feature expression {self.expression!r}")
+
+        return self._evaluate(self._tree.body, values)
+
+    def _evaluate(self, node: ast.AST, values: Mapping[str, Any]) -> Any:
+        if isinstance(node, ast.BinOp):
+            return _BINARY_OPERATORS[type(node.op)](
+                self._evaluate(node.left, values),
+                self._evaluate(node.right, values),
+            )
+        if isinstance(node, ast.UnaryOp):
+            return _UNARY_OPERATORS[type(node.op)](self._evaluate(node.operand, values))
+        if isinstance(node, ast.Call):
+            return _FUNCTIONS[node.func.id](*(self._evaluate(arg, values) for arg in node.args))  # type: ignore
+        if isinstance(node, ast.Name):
+            return values[node.id]
+        # constants are floats so integer arithmetic can not grow without bound
+        return float(node.value)  # type: ignore
+
+
+def compute_features(expressions: Mapping[str, FeatureExpression], values: Mapping[str, Any]) -> Dict[str, Any]:
+    """Computes features from expressions. Expressions are evaluated against the
+    given values only, so computed features cannot reference each other.
+
+    Args:
+        expressions:
+            Mapping of computed feature names to expressions.
+        values:
+            Mapping of feature names to values (scalars or numpy arrays).
+
+    Returns:
+        Mapping of computed feature names to computed values
+    """
+    return {feature: expression.evaluate(values) for feature, expression in expressions.items()}
@@ -17,8 +17,16 @@ class AlertType(str, Enum):
     Alternating = "Alternating"
     AllGood = "All Good"
     Trend = "Trend"
+    Ewma = "EWMA"
+    Cusum = "CUSUM"
 
 
 class ProducerTypes(str, Enum):
     Kafka = "Kafka"
     Http = "http"
+    Local = "local"
+
+
+class SamplingStrategy(str, Enum):
+    Uniform = "uniform"
+    Reservoir = "reservoir"
@@ -2,12 +2,15 @@ mod py_scouter;
 use py_scouter::_scouter::{ScouterDrifter, ScouterProfiler};
 use pyo3::prelude::*;
 use scouter::utils::cron::{
-    CommonCron, Every12Hours, Every30Minutes, Every6Hours, EveryDay, EveryHour, EveryWeek,
+    CommonCron, CronSchedule, Every12Hours, Every30Minutes, Every6Hours, EveryDay, EveryHour,
+    EveryWeek,
 };
 use scouter::utils::types::{
-    Alert, AlertRule, AlertType, AlertZone, DataProfile, Distinct, DriftConfig, DriftMap,
-    DriftProfile, DriftServerRecord, FeatureAlert, FeatureAlerts, FeatureDataProfile, FeatureDrift,
-    FeatureDriftProfile, Histogram, PercentageAlertRule, ProcessAlertRule,
+    Alert, AlertRule, AlertType, AlertZone, BooleanStats, CusumAlertRule, DataProfile,
+    DataProfileComparison, Distinct, DriftConfig, DriftMap, DriftProfile, DriftScore,
+    DriftServerRecord, EwmaAlertRule, FeatureAlert, FeatureAlerts, FeatureDataProfile,
+    FeatureDrift, FeatureDriftProfile, FeatureProfileChange, Histogram, PercentageAlertRule,
+    ProcessAlertRule, TemporalStats,
 };
 
 #[pymodule]
@@ -18,9 +21,14 @@ fn _scouter(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<FeatureDriftProfile>()?;
     m.add_class::<DataProfile>()?;
     m.add_class::<FeatureDataProfile>()?;
+    m.add_class::<DataProfileComparison>()?;
+    m.add_class::<FeatureProfileChange>()?;
     m.add_class::<Distinct>()?;
     m.add_class::<Histogram>()?;
+    m.add_class::<BooleanStats>()?;
+    m.add_class::<TemporalStats>()?;
     m.add_class::<DriftMap>()?;
+    m.add_class::<DriftScore>()?;
     m.add_class::<FeatureDrift>()?;
     m.add_class::<AlertRule>()?;
     m.add_class::<DriftConfig>()?;
@@ -32,6 +40,8 @@ fn _scouter(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<FeatureAlert>()?;
     m.add_class::<ProcessAlertRule>()?;
     m.add_class::<PercentageAlertRule>()?;
+    m.add_class::<EwmaAlertRule>()?;
+    m.add_class::<CusumAlertRule>()?;
     m.add_class::<Every30Minutes>()?;
     m.add_class::<EveryHour>()?;
     m.add_class::<Every6Hours>()?;
@@ -39,6 +49,7 @@ fn _scouter(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<EveryDay>()?;
     m.add_class::<EveryWeek>()?;
     m.add_class::<CommonCron>()?;
+    m.add_class::<CronSchedule>()?;
     m.add_class::<DriftServerRecord>()?;
     Ok(())
 }
@@ -3,13 +3,15 @@ use scouter::core::alert::generate_alerts;
 use scouter::core::monitor::Monitor;
 use scouter::core::profiler::Profiler;
 use scouter::utils::types::{
-    AlertRule, DataProfile, DriftConfig, DriftMap, DriftProfile, DriftServerRecord, FeatureAlerts,
+    AlertRule, DataProfile, DriftConfig, DriftMap, DriftProfile, DriftScore, DriftServerRecord,
+    FeatureAlerts,
 };
 
 use numpy::PyReadonlyArray2;
 use pyo3::exceptions::PyValueError;
 
 use pyo3::prelude::*;
+use std::collections::HashMap;
 
 #[pyclass]
 pub struct ScouterProfiler {
@@ -26,15 +28,26 @@ impl ScouterProfiler {
         }
     }
 
+    #[pyo3(signature = (features, array, bin_size, approx_distinct=false, boolean_features=None, datetime_features=None))]
     pub fn create_data_profile_f32(
         &mut self,
         features: Vec<String>,
         array: PyReadonlyArray2<f32>,
         bin_size: usize,
+        approx_distinct: bool,
+        boolean_features: Option<Vec<String>>,
+        datetime_features: Option<Vec<String>>,
     ) -> PyResult<DataProfile> {
         let array = array.as_array();
 
-        let profile = match self.profiler.compute_stats(&features, &array, &bin_size) {
+        let profile = match self.profiler.compute_stats(
+            &features,
+            &array,
+            &bin_size,
+            approx_distinct,
+            &boolean_features.unwrap_or_default(),
+            &datetime_features.unwrap_or_default(),
+        ) {
             Ok(profile) => profile,
             Err(_e) => {
                 return Err(PyValueError::new_err(
@@ -46,15 +59,26 @@ impl ScouterProfiler {
         Ok(profile)
     }
 
+    #[pyo3(signature = (features, array, bin_size, approx_distinct=false, boolean_features=None, datetime_features=None))]
     pub fn create_data_profile_f64(
         &mut self,
         features: Vec<String>,
         array: PyReadonlyArray2<f64>,
         bin_size: usize,
+        approx_distinct: bool,
+        boolean_features: Option<Vec<String>>,
+        datetime_features: Option<Vec<String>>,
     ) -> PyResult<DataProfile> {
         let array = array.as_array();
 
-        let profile = match self.profiler.compute_stats(&features, &array, &bin_size) {
+        let profile = match self.profiler.compute_stats(
+            &features,
+            &array,
+            &bin_size,
+            approx_distinct,
+            &boolean_features.unwrap_or_default(),
+            &datetime_features.unwrap_or_default(),
+        ) {
             Ok(profile) => profile,
             Err(_e) => {
                 return Err(PyValueError::new_err(
@@ -166,6 +190,24 @@ impl ScouterDrifter {
         Ok(drift_map)
     }
 
+    pub fn compute_drift_score(
+        &mut self,
+        drift_map: DriftMap,
+        drift_profile: DriftProfile,
+    ) -> PyResult<DriftScore> {
+        let score = match self.monitor.compute_drift_score(&drift_map, &drift_profile) {
+            Ok(score) => score,
+            Err(e) => {
+                return Err(PyValueError::new_err(format!(
+                    "Failed to compute drift score: {}",
+                    e
+                )));
+            }
+        };
+
+        Ok(score)
+    }
+
     pub fn generate_alerts(
         &mut self,
         drift_array: PyReadonlyArray2<f64>,
@@ -219,4 +261,105 @@ impl ScouterDrifter {
 
         Ok(profile)
     }
+
+    pub fn sample_batches_f64(
+        &mut self,
+        py: Python<'_>,
+        features: Vec<String>,
+        arrays: Vec<PyReadonlyArray2<f64>>,
+        drift_profile: DriftProfile,
+    ) -> PyResult<Vec<DriftServerRecord>> {
+        let arrays = arrays
+            .iter()
+            .map(|array| array.as_array())
+            .collect::<Vec<_>>();
+
+        // batches are sampled in parallel, release the GIL so other python threads keep running
+        let monitor = &self.monitor;
+        let records =
+            match py.allow_threads(|| monitor.sample_batches(&features, &arrays, &drift_profile)) {
+                Ok(records) => records,
+                Err(_e) => {
+                    return Err(PyValueError::new_err("Failed to sample batches"));
+                }
+            };
+
+        Ok(records)
+    }
+
+    #[pyo3(signature = (features, array, drift_profile, num_windows=1000, window_size=10, seed=None))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn estimate_false_positive_rate_f32(
+        &mut self,
+        py: Python<'_>,
+        features: Vec<String>,
+        array: PyReadonlyArray2<f32>,
+        drift_profile: DriftProfile,
+        num_windows: usize,
+        window_size: usize,
+        seed: Option<u64>,
+    ) -> PyResult<HashMap<String, f64>> {
+        let array = array.as_array();
+
+        // windows are simulated in parallel, release the GIL so other python threads keep running
+        let monitor = &self.monitor;
+        let rates = match py.allow_threads(|| {
+            monitor.estimate_false_positive_rate(
+                &features,
+                &array,
+                &drift_profile,
+                num_windows,
+                window_size,
+                seed,
+            )
+        }) {
+            Ok(rates) => rates,
+            Err(e) => {
+                return Err(PyValueError::new_err(format!(
+                    "Failed to estimate false positive rate: {}",
+                    e
+                )));
+            }
+        };
+
+        Ok(rates)
+    }
+
+    #[pyo3(signature = (features, array, drift_profile, num_windows=1000, window_size=10, seed=None))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn estimate_false_positive_rate_f64(
+        &mut self,
+        py: Python<'_>,
+        features: Vec<String>,
+        array: PyReadonlyArray2<f64>,
+        drift_profile: DriftProfile,
+        num_windows: usize,
+        window_size: usize,
+        seed: Option<u64>,
+    ) -> PyResult<HashMap<String, f64>> {
+        let array = array.as_array();
+
+        // windows are simulated in parallel, release the GIL so other python threads keep running
+        let monitor = &self.monitor;
+        let rates = match py.allow_threads(|| {
+            monitor.estimate_false_positive_rate(
+                &features,
+                &array,
+                &drift_profile,
+                num_windows,
+                window_size,
+                seed,
+            )
+        }) {
+            Ok(rates) => rates,
+            Err(e) => {
+                return Err(PyValueError::new_err(format!(
+                    "Failed to estimate false positive rate: {}",
+                    e
+                )));
+            }
+        };
+
+        Ok(rates)
+    }
 }
@@ -8,6 +8,8 @@ from scouter._scouter import (
     DriftMap,
     DriftConfig,
     AlertRule,
+    CusumAlertRule,
+    EwmaAlertRule,
 )
 
 
@@ -163,3 +165,98 @@ def test_alerts_percentage(array: NDArray, monitor_config_percentage: DriftConfi
     # should have no alerts
     assert len(alerts.features["feature_0"].alerts) == 1
     assert len(alerts.features["feature_0"].indices[1]) == 2
+
+
+def test_drift_dataframes(array: NDArray, monitor_config_percentage: DriftConfig):
+    scouter = Drifter()
+
+    profile: DriftProfile = scouter.create_drift_profile(array, monitor_config_percentage)
+    drift_map: DriftMap = scouter.compute_drift(array, profile)
+
+    num_rows = sum(len(feature.drift) for feature in drift_map.features.values())
+
+    pandas_df = drift_map.to_pandas()
+    assert list(pandas_df.columns) == ["feature", "sample", "drift"]
+    assert len(pandas_df) == num_rows
+
+    polars_df = drift_map.to_polars()
+    assert polars_df.columns == ["feature", "sample", "drift"]
+    assert polars_df.height == num_rows
+
+    drift_array, features = drift_map.to_numpy()
+    drift_array[0, 0] = 1.0
+    drift_array[8, 0] = 1.0
+
+    alerts = scouter.generate_alerts(drift_array, features, monitor_config_percentage.alert_rule)
+
+    alerts_df = alerts.to_polars()
+    assert alerts_df.columns == ["feature", "kind", "zone"]
+    assert "feature_0" in alerts_df["feature"].to_list()
+    assert len(alerts.to_pandas()) == alerts_df.height
+
+
+def test_drift_score(array: NDArray, monitor_config: DriftConfig):
+    scouter = Drifter()
+
+    profile: DriftProfile = scouter.create_drift_profile(
+        array,
+        monitor_config,
+        feature_importance={"feature_0": 0.7, "feature_1": 0.3},
+    )
+    assert profile.feature_weights == {"feature_0": 0.7, "feature_1": 0.3}
+
+    drift_map: DriftMap = scouter.compute_drift(array, profile)
+    drift_score = scouter.compute_drift_score(drift_map, profile)
+
+    assert 0.0 <= drift_score.score <= 1.0
+    assert len(drift_score.trend) == len(drift_map.features["feature_0"].drift)
+
+    profile.feature_weights = {"missing": 1.0}
+    with pytest.raises(ValueError):
+        scouter.compute_drift_score(drift_map, profile)
+
+
+def test_estimate_false_positive_rate(array: NDArray, monitor_config: DriftConfig):
+    drifter = Drifter()
+    profile: DriftProfile = drifter.create_drift_profile(array, monitor_config)
+
+    rates = drifter.estimate_false_positive_rate(array, profile, num_windows=100, seed=42)
+    assert set(rates) == set(profile.features)
+    assert all(0.0 <= rate <= 1.0 for rate in rates.values())
+
+    assert rates == drifter.estimate_false_positive_rate(array, profile, num_windows=100, seed=42)
+
+    with pytest.raises(ValueError):
+        drifter.estimate_false_positive_rate(array, profile, num_windows=0)
+
+
+@pytest.mark.parametrize(
+    "alert_rule",
+    [AlertRule(ewma_rule=EwmaAlertRule()), AlertRule(cusum_rule=CusumAlertRule(k=0.5, h=4.0))],
+)
+def test_alerts_chart(array: NDArray, alert_rule: AlertRule):
+    config = DriftConfig(name="test", repository="test", alert_rule=alert_rule)
+
+    scouter = Drifter()
+    profile: DriftProfile = scouter.create_drift_profile(array, config)
+
+    # sustained shift of feature_0 that stays within the zone 2 limit
+    shifted = array.copy()
+    shifted[:, 0] += 0.5
+
+    drift_map: DriftMap = scouter.compute_drift(shifted, profile)
+    drift_array, features = drift_map.to_numpy()
+
+    alerts = scouter.generate_alerts(drift_array, features, alert_rule)
+    assert len(alerts.features["feature_0"].alerts) == 1
+
+
+def test_chart_rule_validation():
+    assert EwmaAlertRule().lambda_ == 0.2
+    assert CusumAlertRule().h == 5.0
+
+    with pytest.raises(ValueError):
+        EwmaAlertRule(lambda_=1.5)
+
+    with pytest.raises(ValueError):
+        CusumAlertRule(h=-1.0)
@@ -74,3 +74,92 @@ def test_int(array: NDArray, monitor_config: DriftConfig):
     assert profile.features["feature_0"].center == pytest.approx(1.0, 0.1)
     assert profile.features["feature_1"].center == pytest.approx(2.0, 0.1)
     assert profile.features["feature_2"].center == pytest.approx(3.0, 0.1)
+
+
+def test_monitor_polars_lazyframe(array: NDArray, monitor_config: DriftConfig):
+    df = pl.from_numpy(array).lazy()
+    scouter = Drifter()
+    profile: DriftProfile = scouter.create_drift_profile(df, monitor_config)
+
+    assert profile.features["column_0"].center == pytest.approx(1.5, 0.1)
+    assert profile.features["column_1"].center == pytest.approx(2.5, 0.1)
+    assert profile.features["column_2"].center == pytest.approx(3.5, 0.1)
+
+
+def test_monitor_arrow(array: NDArray, monitor_config: DriftConfig):
+    pa = pytest.importorskip("pyarrow")
+    table = pa.Table.from_pandas(pd.DataFrame(array, columns=["a", "b", "c"]))
+    reader = pa.RecordBatchReader.from_batches(table.schema, table.to_batches(max_chunksize=100))
+
+    scouter = Drifter()
+    profile: DriftProfile = scouter.create_drift_profile(reader, monitor_config)
+
+    assert profile.features["a"].center == pytest.approx(1.5, 0.1)
+    assert profile.features["b"].center == pytest.approx(2.5, 0.1)
+    assert profile.features["c"].center == pytest.approx(3.5, 0.1)
+
+
+def test_monitor_feature_aliases(array: NDArray, monitor_config: DriftConfig):
+    df = pl.from_numpy(array)
+    scouter = Drifter()
+    profile: DriftProfile = scouter.create_drift_profile(df, monitor_config)
+
+    with pytest.raises(ValueError):
+        profile.add_feature_alias("renamed", "missing")
+
+    profile.add_feature_alias("renamed", "column_0")
+    assert profile.feature_aliases == {"renamed": "column_0"}
+
+    # aliases can not shadow a profile feature or point at another alias
+    with pytest.raises(ValueError):
+        profile.add_feature_alias("column_1", "column_0")
+
+    with pytest.raises(ValueError):
+        profile.add_feature_alias("renamed_again", "renamed")
+
+    drift_map = scouter.compute_drift(df.rename({"column_0": "renamed"}), profile)
+    assert "column_0" in drift_map.features
+    assert "renamed" not in drift_map.features
+
+
+def test_monitor_sample_batches(array: NDArray, monitor_config: DriftConfig):
+    pa = pytest.importorskip("pyarrow")
+
+    table = pa.Table.from_pandas(pd.DataFrame(array, columns=["a", "b", "c"]))
+
+    scouter = Drifter()
+    profile: DriftProfile = scouter.create_drift_profile(table, monitor_config)
+
+    batches = table.to_batches(max_chunksize=100)
+    chunks = list(scouter.sample_batches(batches, profile, batches_per_chunk=4))
+
+    # 10 batches sampled in chunks of 4, 4 and 2
+    assert len(chunks) == 3
+
+    samples_per_batch = -(-100 // profile.config.sample_size)
+    records = [record for chunk in chunks for record in chunk]
+    assert len(records) == 10 * samples_per_batch * 3
+    assert {record.feature for record in records} == {"a", "b", "c"}
+
+    # rows left over at the end of a batch are carried into the next one, so uneven
+    # batches are sampled the same as the whole table
+    uneven = table.to_batches(max_chunksize=profile.config.sample_size + 1)
+    records = [record for chunk in scouter.sample_batches(uneven, profile) for record in chunk]
+    assert len(records) == -(-table.num_rows // profile.config.sample_size) * 3
+
+
+def test_monitor_sample_batches_computed_features(array: NDArray, monitor_config: DriftConfig):
+    pa = pytest.importorskip("pyarrow")
+
+    table = pa.Table.from_pandas(pd.DataFrame(array, columns=["a", "b", "c"]))
+
+    scouter = Drifter()
+    profile: DriftProfile = scouter.create_drift_profile(
+        table,
+        monitor_config,
+        computed_features={"a_plus_b": "a + b"},
+    )
+
+    batches = table.to_batches(max_chunksize=100)
+    records = [record for chunk in scouter.sample_batches(batches, profile) for record in chunk]
+    assert {record.feature for record in records} == {"a", "b", "c", "a_plus_b"}
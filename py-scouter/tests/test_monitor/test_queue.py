@@ -1,5 +1,19 @@
-from scouter import MonitorQueue, DriftConfig, DriftProfile, Drifter, KafkaConfig
+import asyncio
+import threading
+import time
+from typing import Optional
+from scouter import (
+    BaseProducer,
+    MonitorQueue,
+    DriftConfig,
+    DriftProfile,
+    DriftServerRecord,
+    Drifter,
+    KafkaConfig,
+)
+import pytest
 import pandas as pd
+import polars as pl
 
 
 def test_monitor_pandas(
@@ -34,3 +48,301 @@ def test_monitor_pandas(
 
     records = return_record(records)
     assert len(records) == 3
+
+
+def test_monitor_sample_rate(
+    pandas_dataframe: pd.DataFrame,
+    monitor_config: DriftConfig,
+    mock_kafka_producer,
+):
+    scouter = Drifter()
+    profile: DriftProfile = scouter.create_drift_profile(pandas_dataframe, monitor_config)
+
+    kafka_config = KafkaConfig(
+        topic="test-topic",
+        brokers="localhost:9092",
+        raise_on_err=True,
+    )
+
+    with pytest.raises(ValueError):
+        MonitorQueue(drift_profile=profile, config=kafka_config, sample_rate=0.0)
+
+    queue = MonitorQueue(drift_profile=profile, config=kafka_config, sample_rate=0.5, seed=42)
+    seeded = MonitorQueue(drift_profile=profile, config=kafka_config, sample_rate=0.5, seed=42)
+
+    for record in pandas_dataframe.to_dict(orient="records"):
+        queue.insert(record)
+        seeded.insert(record)
+
+    assert queue.total_count == len(pandas_dataframe)
+    assert 0 < queue.sampled_count < queue.total_count
+    assert seeded.sampled_count == queue.sampled_count
+
+
+def test_monitor_reservoir_sampling(
+    pandas_dataframe: pd.DataFrame,
+    monitor_config: DriftConfig,
+    mock_kafka_producer,
+):
+    scouter = Drifter()
+    profile: DriftProfile = scouter.create_drift_profile(pandas_dataframe, monitor_config)
+
+    kafka_config = KafkaConfig(
+        topic="test-topic",
+        brokers="localhost:9092",
+        raise_on_err=True,
+    )
+
+    queue = MonitorQueue(
+        drift_profile=profile,
+        config=kafka_config,
+        sample_rate=0.25,
+        sampling="reservoir",
+        seed=42,
+    )
+
+    # windows of 100 inserts publish a sample of 25 inserts each
+    published = [queue.insert(record) for record in pandas_dataframe[0:200].to_dict(orient="records")]
+    records = [record for records in published if records for record in records]
+
+    assert len(records) == 2 * len(profile.features)
+    assert published[99] and published[199]
+    assert queue.total_count == 200
+    assert queue.sampled_count == 50
+
+    # batches are sampled into the same windows
+    records = queue.insert_batch(pandas_dataframe[200:300])
+    assert len(records) == len(profile.features)
+    assert queue.sampled_count == 75
+
+
+def test_monitor_try_insert_flush(
+    pandas_dataframe: pd.DataFrame,
+    monitor_config: DriftConfig,
+    mock_kafka_producer,
+):
+    scouter = Drifter()
+    profile: DriftProfile = scouter.create_drift_profile(pandas_dataframe, monitor_config)
+
+    kafka_config = KafkaConfig(
+        topic="test-topic",
+        brokers="localhost:9092",
+        raise_on_err=True,
+    )
+
+    queue = MonitorQueue(drift_profile=profile, config=kafka_config)
+
+    for record in pandas_dataframe[0:30].to_dict(orient="records"):
+        assert queue.try_insert(record)
+
+    # 25 records are published by the worker, the remaining 5 on flush
+    records = queue.flush()
+    assert len(records) == 3
+    assert queue.sampled_count == 30
+
+    assert asyncio.run(queue.flush_async()) == []
+
+
+class StalledProducer(BaseProducer):
+    """Producer whose publish blocks until released, to stall the background worker."""
+
+    def __init__(self) -> None:
+        self.publishing = threading.Event()
+        self.release = threading.Event()
+
+    def publish(self, record: DriftServerRecord) -> None:
+        self.publishing.set()
+        self.release.wait(timeout=10)
+
+    def flush(self, timeout: Optional[float] = None) -> None:
+        pass
+
+    @staticmethod
+    def type() -> str:
+        return "stalled"
+
+
+def test_monitor_try_insert_drops(
+    pandas_dataframe: pd.DataFrame,
+    monitor_config: DriftConfig,
+):
+    scouter = Drifter()
+    profile: DriftProfile = scouter.create_drift_profile(pandas_dataframe, monitor_config)
+
+    producer = StalledProducer()
+    queue = MonitorQueue(drift_profile=profile, config=producer, max_pending=1)
+    records = pandas_dataframe[0:28].to_dict(orient="records")
+
+    # the 25th insert makes the worker publish, which stalls
+    for record in records[:24]:
+        queue.insert(record)
+    assert queue.try_insert(records[24])
+    assert producer.publishing.wait(timeout=10)
+
+    # one insert fits in the pending queue, the next one is dropped
+    assert queue.try_insert(records[25])
+    assert not queue.try_insert(records[26])
+    assert queue.dropped_count == 1
+    assert queue.pending_count == 2
+
+    # direct inserts wait for the stalled publish
+    blocked = threading.Thread(target=queue.insert, args=(records[27],))
+    blocked.start()
+
+    deadline = time.monotonic() + 10
+    while queue.blocked_count == 0 and time.monotonic() < deadline:
+        time.sleep(0.01)
+    assert queue.blocked_count == 1
+
+    with pytest.raises(TimeoutError):
+        queue.flush(timeout=0.1)
+
+    producer.release.set()
+    blocked.join(timeout=10)
+
+    # 24 + 1 records are published by the worker, the remaining 2 on flush
+    assert len(queue.flush(timeout=10)) == len(profile.features)
+    assert queue.pending_count == 0
+    assert queue.sampled_count == 27
+
+
+def test_monitor_insert_batch(
+    pandas_dataframe: pd.DataFrame,
+    monitor_config: DriftConfig,
+    mock_kafka_producer,
+):
+    scouter = Drifter()
+    profile: DriftProfile = scouter.create_drift_profile(pandas_dataframe, monitor_config)
+
+    kafka_config = KafkaConfig(
+        topic="test-topic",
+        brokers="localhost:9092",
+        raise_on_err=True,
+    )
+
+    queue = MonitorQueue(drift_profile=profile, config=kafka_config)
+
+    # 2 complete samples of 25 rows, 10 rows left in the queue
+    records = queue.insert_batch(pandas_dataframe[0:60])
+    assert len(records) == 6
+    assert all(len(values) == 10 for values in queue.feature_queue.values())
+
+    # columns as a dictionary of arrays
+    batch = pandas_dataframe[60:75]
+    records = queue.insert_batch({column: batch[column].to_numpy() for column in batch.columns})
+    assert len(records) == 3
+    assert all(len(values) == 0 for values in queue.feature_queue.values())
+
+    assert queue.insert_batch(pl.from_pandas(pandas_dataframe[75:80])) == []
+    assert queue.total_count == 80
+
+
+def test_monitor_computed_features(
+    pandas_dataframe: pd.DataFrame,
+    monitor_config: DriftConfig,
+    mock_kafka_producer,
+):
+    scouter = Drifter()
+    profile: DriftProfile = scouter.create_drift_profile(
+        pandas_dataframe,
+        monitor_config,
+        computed_features={"column_sum": "column_0 + column_1"},
+    )
+
+    assert profile.computed_features == {"column_sum": "column_0 + column_1"}
+    assert "column_sum" in profile.features
+
+    drift_map = scouter.compute_drift(pandas_dataframe, profile)
+    assert "column_sum" in drift_map.features
+
+    kafka_config = KafkaConfig(
+        topic="test-topic",
+        brokers="localhost:9092",
+        raise_on_err=True,
+    )
+
+    queue = MonitorQueue(drift_profile=profile, config=kafka_config)
+
+    record = pandas_dataframe.iloc[0].to_dict()
+    queue.insert(record)
+    assert queue.feature_queue["column_sum"] == [pytest.approx(record["column_0"] + record["column_1"])]
+
+    # 1 row in the queue, 25 rows complete a sample
+    records = queue.insert_batch(pandas_dataframe[1:25])
+    assert len(records) == 4
+
+
+def test_monitor_computed_string_length(
+    monitor_config: DriftConfig,
+    mock_kafka_producer,
+):
+    names = [f"user-{i}" * (i % 4 + 1) for i in range(100)]
+    data = pd.DataFrame(
+        {
+            "amount": [float(i) for i in range(100)],
+            "name_len": [float(len(name)) for name in names],
+        }
+    )
+
+    scouter = Drifter()
+    profile: DriftProfile = scouter.create_drift_profile(
+        data,
+        monitor_config,
+        computed_features={"name_len": "len(name)"},
+    )
+
+    kafka_config = KafkaConfig(
+        topic="test-topic",
+        brokers="localhost:9092",
+        raise_on_err=True,
+    )
+
+    queue = MonitorQueue(drift_profile=profile, config=kafka_config)
+
+    # raw string inputs are only used to compute the feature
+    queue.insert({"amount": 1.0, "name": "abcd"})
+    assert queue.feature_queue["name_len"] == [4]
+    assert "name" not in queue.feature_queue
+
+    records = queue.insert_batch(pl.DataFrame({"amount": data["amount"][1:25].tolist(), "name": names[1:25]}))
+    assert {record.feature for record in records} == {"amount", "name_len"}
+
+    # unknown keys that are not inputs of computed features are still rejected
+    with pytest.raises(KeyError):
+        queue.insert({"amount": 1.0, "name": "abcd", "unknown": 1.0})
+
+
+def test_monitor_close(
+    pandas_dataframe: pd.DataFrame,
+    monitor_config: DriftConfig,
+):
+    scouter = Drifter()
+    profile: DriftProfile = scouter.create_drift_profile(pandas_dataframe, monitor_config)
+
+    producer = StalledProducer()
+    queue = MonitorQueue(drift_profile=profile, config=producer)
+    records = pandas_dataframe[0:27].to_dict(orient="records")
+
+    # the 25th insert publishes and stalls while holding the queue lock
+    inserter = threading.Thread(target=lambda: [queue.insert(record) for record in records[:25]])
+    inserter.start()
+    assert producer.publishing.wait(timeout=10)
+
+    # starting the worker does not wait for the publish
+    start = time.monotonic()
+    assert queue.try_insert(records[25])
+    assert queue.try_insert(records[26])
+    assert time.monotonic() - start < 1
+
+    producer.release.set()
+    inserter.join(timeout=10)
+
+    # pending inserts are processed before the worker stops
+    published = queue.close(timeout=10)
+    assert len(published) == 3
+    assert queue.pending_count == 0
+    assert queue.sampled_count == 27
+    assert queue._worker is not None and not queue._worker.is_alive()
+
+    with pytest.raises(RuntimeError):
+        queue.try_insert(records[0])
@@ -1,4 +1,12 @@
 from scouter import HTTPConfig, HTTPProducer, DriftServerRecord
+from scouter.integrations.http import ServerConnectionError, ServerResponseError
+from scouter.utils.circuit_breaker import (
+    CircuitBreaker,
+    CircuitBreakerOpenError,
+    CircuitState,
+)
+from httpx import ConnectError, ReadError, Response
+import pytest
 
 
 def test_http_config():
@@ -32,3 +40,187 @@ def test_http_producer(mock_httpx_producer):
     )
 
     producer.publish(record)
+
+
+def test_http_producer_circuit_breaker(mock_httpx_producer):
+    config = HTTPConfig(
+        server_url="http://localhost:8000",
+        username="test-username",
+        password="test-password",
+        max_retries=2,
+        retry_initial_wait=0,
+        retry_max_wait=0,
+        circuit_failure_threshold=1,
+        circuit_reset_timeout=60,
+    )
+
+    producer = HTTPProducer(config)
+    assert producer.circuit_state == CircuitState.Closed
+
+    record = DriftServerRecord(
+        name="test",
+        repository="test",
+        version="1.0.0",
+        feature="test",
+        value=0.1,
+    )
+
+    # server starts failing
+    mock_httpx_producer.return_value.post.return_value = Response(
+        status_code=500,
+        json={"detail": "server error"},
+    )
+
+    with pytest.raises(ValueError):
+        producer.publish(record)
+
+    assert producer.circuit_state == CircuitState.Open
+    calls = mock_httpx_producer.return_value.post.call_count
+
+    # open circuit short-circuits without calling the server
+    with pytest.raises(CircuitBreakerOpenError):
+        producer.publish(record)
+
+    assert mock_httpx_producer.return_value.post.call_count == calls
+
+
+def test_circuit_breaker():
+    breaker = CircuitBreaker(failure_threshold=2, reset_timeout=0)
+
+    breaker.record_failure()
+    assert breaker.state == CircuitState.Closed
+
+    breaker.record_failure()
+    # reset timeout of 0 moves straight to half open
+    assert breaker.state == CircuitState.HalfOpen
+    assert breaker.allow_request()
+
+    # only a single trial request is allowed while half open
+    assert not breaker.allow_request()
+
+    breaker.record_failure()
+    breaker.reset_timeout = 60
+    assert breaker.state == CircuitState.Open
+    assert not breaker.allow_request()
+
+    breaker.record_success()
+    assert breaker.state == CircuitState.Closed
+
+
+def test_http_producer_request_id(mock_httpx_producer):
+    config = HTTPConfig(
+        server_url="http://localhost:8000",
+        username="test-username",
+        password="test-password",
+    )
+
+    producer = HTTPProducer(config)
+    producer.publish(
+        DriftServerRecord(
+            name="test",
+            repository="test",
+            version="1.0.0",
+            feature="test",
+            value=0.1,
+        )
+    )
+
+    headers = mock_httpx_producer.return_value.post.call_args.kwargs["headers"]
+    assert len(headers["x-request-id"]) == 36
+    assert producer.last_request_id == headers["x-request-id"]
+
+
+def test_http_producer_retries_server_errors_only(mock_httpx_producer):
+    config = HTTPConfig(
+        server_url="http://localhost:8000",
+        username="test-username",
+        password="test-password",
+        max_retries=3,
+        retry_initial_wait=0,
+        retry_max_wait=0,
+        circuit_failure_threshold=1,
+    )
+
+    producer = HTTPProducer(config)
+    post = mock_httpx_producer.return_value.post
+    record = DriftServerRecord(
+        name="test",
+        repository="test",
+        version="1.0.0",
+        feature="test",
+        value=0.1,
+    )
+
+    # client errors are not retried, do not refresh the token and do not open the circuit
+    post.return_value = Response(status_code=422, json={"detail": "invalid record"})
+    calls = post.call_count
+
+    with pytest.raises(ServerResponseError):
+        producer.publish(record)
+
+    assert post.call_count == calls + 1
+    assert producer.circuit_state == CircuitState.Closed
+
+    # connection errors are retried and open the circuit
+    post.side_effect = ConnectError("connection refused")
+    calls = post.call_count
+
+    with pytest.raises(ServerConnectionError) as exc_info:
+        producer.publish(record)
+
+    # first attempt plus max_retries retries
+    assert post.call_count == calls + 4
+    assert producer.circuit_state == CircuitState.Open
+
+    # every attempt reuses the request id attached to the error
+    request_ids = {call.kwargs["headers"]["x-request-id"] for call in post.call_args_list[calls:]}
+    assert request_ids == {exc_info.value.request_id}
+
+    with pytest.raises(CircuitBreakerOpenError) as open_info:
+        producer.publish(record)
+
+    assert len(open_info.value.request_id) == 36
+
+
+def test_http_producer_retries_non_json_errors(mock_httpx_producer):
+    config = HTTPConfig(
+        server_url="http://localhost:8000",
+        username="test-username",
+        password="test-password",
+        max_retries=1,
+        retry_initial_wait=0,
+        retry_max_wait=0,
+        circuit_failure_threshold=2,
+    )
+
+    producer = HTTPProducer(config)
+    post = mock_httpx_producer.return_value.post
+    record = DriftServerRecord(
+        name="test",
+        repository="test",
+        version="1.0.0",
+        feature="test",
+        value=0.1,
+    )
+
+    # html error pages from a proxy are retried based on the status code
+    post.return_value = Response(status_code=503, text="<html>Service Unavailable</html>")
+    calls = post.call_count
+
+    with pytest.raises(ServerResponseError) as exc_info:
+        producer.publish(record)
+
+    assert post.call_count == calls + 2
+    assert exc_info.value.status_code == 503
+    assert "Service Unavailable" in str(exc_info.value)
+    assert producer.circuit_breaker.failure_count == 1
+
+    # dropped connections are transport errors and are retried as well
+    post.side_effect = ReadError("connection reset")
+    calls = post.call_count
+
+    with pytest.raises(ServerConnectionError):
+        producer.publish(record)
+
+    assert post.call_count == calls + 2
+    assert producer.circuit_state == CircuitState.Open
@@ -0,0 +1,115 @@
+import json
+from pathlib import Path
+
+import pytest
+from httpx import Response
+from scouter import DriftRecordProducer, DriftServerRecord, HTTPConfig, LocalConfig, LocalProducer
+from scouter.integrations.http import ServerResponseError
+from scouter.utils.types import ProducerTypes
+
+
+def _record(value: float) -> DriftServerRecord:
+    return DriftServerRecord(
+        name="test",
+        repository="test",
+        version="1.0.0",
+        feature="test",
+        value=value,
+    )
+
+
+def test_local_producer(tmp_path: Path):
+    config = LocalConfig(path=tmp_path / "records")
+    producer = DriftRecordProducer.get_producer(config=config)
+    assert producer.type() == ProducerTypes.Local
+
+    producer.publish(_record(0.1))
+    producer.publish(_record(0.2))
+
+    lines = (tmp_path / "records" / "drift_records.jsonl").read_text().splitlines()
+    assert len(lines) == 2
+    assert json.loads(lines[1])["value"] == "0.2"
+
+
+def test_local_producer_sync(tmp_path: Path, mock_httpx_producer):
+    producer = LocalProducer(LocalConfig(path=tmp_path))
+    producer.publish(_record(0.1))
+    producer.publish(_record(0.2))
+
+    http_config = HTTPConfig(
+        server_url="http://localhost:8000",
+        username="test-username",
+        password="test-password",
+    )
+
+    assert producer.sync(http_config) == 2
+    assert not (tmp_path / "drift_records.jsonl").exists()
+
+    # nothing left to sync
+    assert producer.sync(http_config) == 0
+
+
+def test_local_producer_sync_interrupted(tmp_path: Path, mock_httpx_producer):
+    producer = LocalProducer(LocalConfig(path=tmp_path))
+
+    # records from a sync that crashed before finishing
+    producer.publish(_record(0.1))
+    (tmp_path / "drift_records.jsonl").rename(tmp_path / "drift_records.syncing")
+
+    producer.publish(_record(0.2))
+
+    http_config = HTTPConfig(
+        server_url="http://localhost:8000",
+        username="test-username",
+        password="test-password",
+    )
+
+    assert producer.sync(http_config) == 2
+    assert not (tmp_path / "drift_records.syncing").exists()
+    assert not (tmp_path / "drift_records.jsonl").exists()
+
+
+def test_local_producer_sync_rejected(tmp_path: Path, mock_httpx_producer):
+    producer = LocalProducer(LocalConfig(path=tmp_path))
+    producer.publish(_record(0.1))
+    producer.publish(_record(0.2))
+    producer.publish(_record(0.3))
+
+    with (tmp_path / "drift_records.jsonl").open("a") as file:
+        file.write("{not json\n")
+
+    status_codes = {"0.2": 422}
+
+    def post(url, **kwargs):
+        # token requests succeed, records are answered with the status code for their value
+        status_code = status_codes.get(kwargs.get("json", {}).get("value"), 200)
+        return Response(status_code=status_code, json={"access_token": "test-token", "detail": "error"})
+
+    mock_httpx_producer.return_value.post.side_effect = post
+
+    http_config = HTTPConfig(
+        server_url="http://localhost:8000",
+        username="test-username",
+        password="test-password",
+        retry_initial_wait=0,
+        retry_max_wait=0,
+    )
+
+    # rejected and malformed records do not block the records after them
+    assert producer.sync(http_config) == 2
+    assert not (tmp_path / "drift_records.jsonl").exists()
+
+    failed = (tmp_path / "drift_records.failed.jsonl").read_text().splitlines()
+    assert len(failed) == 2
+    assert json.loads(failed[0])["value"] == "0.2"
+    assert failed[1] == "{not json"
+
+    # records are kept for the next sync while the server is unavailable
+    status_codes["0.4"] = 503
+    producer.publish(_record(0.4))
+
+    with pytest.raises(ServerResponseError):
+        producer.sync(http_config)
+
+    assert len((tmp_path / "drift_records.jsonl").read_text().splitlines()) == 1
+    assert len((tmp_path / "drift_records.failed.jsonl").read_text().splitlines()) == 2
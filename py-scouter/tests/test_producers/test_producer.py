@@ -1,4 +1,16 @@
-from scouter import DriftRecordProducer, HTTPConfig, KafkaConfig
+from typing import List, Optional
+
+import pandas as pd
+from scouter import (
+    BaseProducer,
+    DriftConfig,
+    DriftRecordProducer,
+    DriftServerRecord,
+    Drifter,
+    HTTPConfig,
+    KafkaConfig,
+    MonitorQueue,
+)
 from scouter.utils.types import ProducerTypes
 import pytest
 
@@ -26,3 +38,34 @@ def test_kafka_producer(mock_kafka_producer):
 def test_fail_production():
     with pytest.raises(ValueError):
         DriftRecordProducer.get_producer(config="test")
+
+
+class ListProducer(BaseProducer):
+    def __init__(self) -> None:
+        self.records: List[DriftServerRecord] = []
+        self.flushed = False
+
+    def publish(self, record: DriftServerRecord) -> None:
+        self.records.append(record)
+
+    def flush(self, timeout: Optional[float] = None) -> None:
+        self.flushed = True
+
+    @staticmethod
+    def type() -> str:
+        return "list"
+
+
+def test_custom_producer(pandas_dataframe: pd.DataFrame, monitor_config: DriftConfig):
+    producer = ListProducer()
+    assert DriftRecordProducer.get_producer(config=producer) is producer
+
+    profile = Drifter().create_drift_profile(pandas_dataframe, monitor_config)
+    queue = MonitorQueue(drift_profile=profile, config=producer)
+
+    for record in pandas_dataframe[0:30].to_dict(orient="records"):
+        queue.insert(record)
+    queue.flush()
+
+    assert len(producer.records) == 6
+    assert producer.flushed
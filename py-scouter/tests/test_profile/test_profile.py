@@ -1,4 +1,5 @@
 from scouter import Profiler
+import numpy as np
 
 import polars as pl
 import pandas as pd
@@ -78,3 +79,147 @@ def test_data_profile_pandas(array: NDArray):
     assert profile.features["0"].quantiles.q25 == pytest.approx(1.25, 0.1)
     assert profile.features["0"].histogram.bins[0] == pytest.approx(1.00, 0.1)
     assert len(profile.features["0"].histogram.bin_counts) == 20
+
+
+def test_data_profile_polars_lazyframe(array: NDArray):
+    df = pl.from_numpy(array).lazy()
+    scouter = Profiler()
+    profile: DataProfile = scouter.create_data_profile(df)
+
+    assert profile.features["column_0"].mean == pytest.approx(1.5, 0.1)
+    assert profile.features["column_2"].mean == pytest.approx(3.5, 0.1)
+
+
+def test_lazyframe_conversion_slices(array: NDArray):
+    df = pl.from_numpy(array)
+
+    # slices that do not divide the number of rows are copied into place
+    converted = Profiler()._convert_lazy_to_array(df.lazy().filter(pl.col("column_0") > 1.5), slice_size=7)
+    np.testing.assert_array_equal(converted, df.filter(pl.col("column_0") > 1.5).to_numpy())
+
+
+def test_data_profile_arrow(array: NDArray):
+    pa = pytest.importorskip("pyarrow")
+    table = pa.Table.from_pandas(pd.DataFrame(array, columns=["a", "b", "c"]))
+    reader = pa.RecordBatchReader.from_batches(table.schema, table.to_batches(max_chunksize=100))
+
+    scouter = Profiler()
+    profile: DataProfile = scouter.create_data_profile(reader)
+
+    assert profile.features["a"].mean == pytest.approx(1.5, 0.1)
+    assert profile.features["c"].mean == pytest.approx(3.5, 0.1)
+
+    # tables are copied batch by batch into a preallocated array
+    chunked = pa.Table.from_batches(table.to_batches(max_chunksize=100))
+    np.testing.assert_array_equal(scouter._convert_data_to_array(chunked), array)
+
+
+def test_data_profile_boolean_datetime(array: NDArray):
+    df = pd.DataFrame(array, columns=["a", "b", "c"])
+    df["flag"] = df["a"] > 1.5
+    df["event_time"] = pd.date_range("2024-01-01", periods=1000, freq="h")
+
+    scouter = Profiler()
+    profile: DataProfile = scouter.create_data_profile(df)
+
+    # boolean mean is the rate of true values
+    assert profile.features["flag"].mean == pytest.approx(0.5, 0.1)
+    assert profile.features["flag"].min == 0.0
+    assert profile.features["flag"].max == 1.0
+
+    # datetimes are profiled as seconds since epoch
+    assert profile.features["event_time"].min == pd.Timestamp("2024-01-01").timestamp()
+
+    # booleans get true/false counts
+    flag = profile.features["flag"].boolean
+    assert flag.true_count + flag.false_count == 1000
+    assert flag.true_count == int(df["flag"].sum())
+    assert profile.features["a"].boolean is None
+
+    # datetimes get weekday and hour distributions, 2024-01-01 was a Monday
+    temporal = profile.features["event_time"].temporal
+    assert len(temporal.weekday_counts) == 7
+    assert temporal.weekday_counts[0] == 144
+    assert temporal.hour_counts == [42] * 16 + [41] * 8
+
+    polars_profile: DataProfile = scouter.create_data_profile(pl.from_pandas(df))
+    assert polars_profile.features["flag"].mean == pytest.approx(profile.features["flag"].mean)
+    assert polars_profile.features["event_time"].min == profile.features["event_time"].min
+    assert polars_profile.features["flag"].boolean.true_count == flag.true_count
+    assert polars_profile.features["event_time"].temporal.hour_counts == temporal.hour_counts
+
+
+def test_data_profile_missing_values(array: NDArray):
+    flags = pd.array([None if i % 10 == 0 else i % 2 == 1 for i in range(1000)], dtype="boolean")
+    times = pd.Series(pd.date_range("2024-01-01", periods=1000, freq="h"))
+    times[::4] = pd.NaT
+
+    df = pd.DataFrame({"a": array[:, 0], "flag": flags, "event_time": times})
+    scouter = Profiler()
+
+    pa = pytest.importorskip("pyarrow")
+    for data in [df, pl.from_pandas(df), pa.Table.from_pandas(df)]:
+        profile: DataProfile = scouter.create_data_profile(data)
+
+        # nulls are counted and left out of the other stats
+        flag = profile.features["flag"]
+        assert flag.missing_count == 100
+        assert flag.missing_rate == pytest.approx(0.1)
+        assert flag.boolean.null_count == 100
+        assert flag.boolean.true_count == 500
+        assert flag.boolean.false_count == 400
+        assert flag.mean == pytest.approx(500 / 900)
+
+        event_time = profile.features["event_time"]
+        assert event_time.missing_count == 250
+        assert sum(event_time.temporal.hour_counts) == 750
+        assert event_time.min == pd.Timestamp("2024-01-01 01:00").timestamp()
+
+        assert profile.features["a"].missing_count == 0
+        assert profile.features["a"].missing_rate == 0.0
+
+    # missing values in other columns are still rejected
+    df.loc[0, "a"] = np.nan
+    with pytest.raises(ValueError):
+        scouter.create_data_profile(df)
+
+
+def test_data_profile_datetime_fractional_seconds():
+    times = pd.Series(pd.date_range("2024-01-01", periods=100, freq="250ms")).astype("datetime64[us]")
+    df = pd.DataFrame({"event_time": times, "value": np.arange(100, dtype="float64")})
+
+    scouter = Profiler()
+    expected = pd.Timestamp("2024-01-01").timestamp() + 99 * 0.25
+
+    # pandas, polars and numpy keep fractional seconds regardless of resolution
+    for data, feature in [
+        (df, "event_time"),
+        (pl.from_pandas(df), "event_time"),
+        (times.to_numpy().reshape(-1, 1), "feature_0"),
+    ]:
+        assert scouter.create_data_profile(data).features[feature].max == pytest.approx(expected, abs=1e-3)
+
+
+def test_data_profile_approx_distinct(array: NDArray):
+    scouter = Profiler()
+    profile: DataProfile = scouter.create_data_profile(array, approx_distinct=True)
+
+    assert profile.features["feature_0"].distinct.count == pytest.approx(1000, 0.05)
+    assert len(profile.features["feature_0"].distinct.top_values) == 10
+
+
+def test_data_profile_compare(array: NDArray):
+    scouter = Profiler()
+    baseline: DataProfile = scouter.create_data_profile(array)
+
+    shifted = array.copy()
+    shifted[:, 0] += 1.0
+    current: DataProfile = scouter.create_data_profile(shifted[:, :2])
+
+    comparison = baseline.compare(current)
+
+    assert comparison.changed_features() == ["feature_0"]
+    assert comparison.features["feature_0"].mean_shift > 1.0
+    assert not comparison.features["feature_1"].changed
+    assert comparison.missing_features == ["feature_2"]
+    assert comparison.new_features == []
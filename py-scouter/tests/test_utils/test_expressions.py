@@ -0,0 +1,41 @@
+import numpy as np
+import pytest
+from scouter.utils.expressions import FeatureExpression, compute_features
+
+
+def test_feature_expression():
+    expression = FeatureExpression("log1p(income) / (age + 1) - -2")
+    assert expression.features == {"income", "age"}
+
+    value = expression.evaluate({"income": 100.0, "age": 9.0})
+    assert value == pytest.approx(np.log1p(100.0) / 10.0 + 2.0)
+
+    values = expression.evaluate({"income": np.array([0.0, 100.0]), "age": np.array([1.0, 9.0])})
+    np.testing.assert_allclose(values, np.log1p([0.0, 100.0]) / [2.0, 10.0] + 2.0)
+
+    assert FeatureExpression("max(a, b) ** 2").evaluate({"a": 1.0, "b": 3.0}) == 9.0
+
+    # huge powers overflow to inf instead of hanging on integer arithmetic
+    with np.errstate(over="ignore"):
+        assert FeatureExpression("9 ** 9 ** 9").evaluate({}) == np.inf
+        assert FeatureExpression("a ** b").evaluate({"a": 9, "b": 9**9}) == np.inf
+
+    with pytest.raises(ValueError):
+        FeatureExpression("age +")
+
+    with pytest.raises(ValueError):
+        FeatureExpression("__import__('os')")
+
+    with pytest.raises(ValueError):
+        FeatureExpression("age.real")
+
+    with pytest.raises(ValueError):
+        expression.evaluate({"income": 1.0})
+
+
+def test_compute_features():
+    computed = compute_features(
+        {"total": FeatureExpression("a + b"), "ratio": FeatureExpression("a / b")},
+        {"a": 1.0, "b": 4.0},
+    )
+    assert computed == {"total": 5.0, "ratio": 0.25}
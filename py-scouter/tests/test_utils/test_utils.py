@@ -1,9 +1,12 @@
+import pytest
 from scouter import (
     Alert,
     AlertType,
     AlertZone,
     AlertRule,
     CommonCrons,
+    CronSchedule,
+    DriftConfig,
     Every30Minutes,
     EveryHour,
     Every6Hours,
@@ -67,3 +70,18 @@ def test_crons():
     assert CommonCrons.EVERY_12_HOURS == Every12Hours().cron
     assert CommonCrons.EVERY_DAY == EveryDay().cron
     assert CommonCrons.EVERY_WEEK == EveryWeek().cron
+
+
+def test_cron_schedule():
+    schedule = CronSchedule("0 0 * * * *")
+    assert schedule.cron == "0 0 * * * *"
+    assert len(schedule.preview()) == 5
+    assert len(schedule.preview(count=10)) == 10
+    assert schedule.get_next() == schedule.preview(1)[0]
+
+    with pytest.raises(ValueError):
+        CronSchedule("every hour")
+
+    # drift configs fall back to the default schedule
+    config = DriftConfig(name="test", repository="test", schedule="every hour")
+    assert config.schedule == "0 0 0 * * *"